@@ -0,0 +1,72 @@
+use std::process::ExitCode;
+
+use utils::{InputSource, Options, Solution};
+
+const USAGE: &str = "usage: aoc (all | --day <N>...) [--part <1|2>] [--input <PATH|->]
+           [--param <KEY=VALUE>]... [--check] [--record]
+           [--time] [--repeat <N>] [--save <FILE>] [--compare <FILE>]
+           [--threshold <PERCENT>]";
+
+fn solutions() -> Vec<Solution> {
+    vec![
+        Solution::new::<day1::Day1>(1),
+        Solution::new::<day2::Day2>(2),
+        Solution::new::<day3::Day3>(3),
+        Solution::new::<day4::Day4>(4),
+        Solution::new::<day5::Day5>(5),
+        Solution::new::<day6::Day6>(6),
+        Solution::new::<day7::Day7>(7),
+        Solution::new::<day8::Day8>(8),
+        Solution::new::<day9::Day9>(9),
+        Solution::new::<day10::Day10>(10),
+        Solution::new::<day11::Day11>(11),
+        Solution::new::<day12::Day12>(12),
+        Solution::new::<day13::Day13>(13),
+        Solution::new::<day14::Day14>(14),
+        Solution::new::<day15::Day15>(15),
+        Solution::new::<day16::Day16>(16),
+        Solution::new::<day17::Day17>(17),
+        Solution::new::<day18::Day18>(18),
+        Solution::new::<day19::Day19>(19),
+        Solution::new::<day20::Day20>(20),
+    ]
+}
+
+fn main() -> ExitCode {
+    let options = match Options::from_env() {
+        Ok(options) if options.all || !options.days.is_empty() => options,
+        Ok(_) => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solutions = solutions();
+    if let Some(day) = options
+        .days
+        .iter()
+        .find(|day| !solutions.iter().any(|s| s.day == **day))
+    {
+        eprintln!("no solution registered for day {day}");
+        return ExitCode::FAILURE;
+    }
+
+    let selected = solutions
+        .into_iter()
+        .filter(|s| options.all || options.days.contains(&s.day))
+        .collect::<Vec<_>>();
+
+    if options.input != InputSource::Default && selected.len() > 1 {
+        eprintln!("--input can only be used with a single day");
+        return ExitCode::FAILURE;
+    }
+
+    match utils::run_solutions(&selected, &options).is_ok() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
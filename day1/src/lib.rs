@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use iter_tools::Itertools;
+use utils::{AdventOfCode, AocError, AocResult};
+
+fn parse_input(input: &str) -> AocResult<(Vec<usize>, Vec<usize>)> {
+    return input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.split_whitespace()
+                .filter_map(|chars| chars.parse::<usize>().ok())
+                .collect_tuple::<(usize, usize)>()
+                .ok_or_else(|| {
+                    AocError::parse(format!("expected two numbers, found {line:?}")).at_line(i + 1)
+                })
+        })
+        .collect();
+}
+
+pub struct Day1;
+
+impl AdventOfCode for Day1 {
+    type Parsed = (Vec<usize>, Vec<usize>);
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return parse_input(input);
+    }
+
+    fn part1((left, right): &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        let mut left = left.clone();
+        let mut right = right.clone();
+
+        left.sort();
+        right.sort();
+
+        return Ok(left
+            .iter()
+            .zip(right.iter())
+            .map(|(left, right)| left.abs_diff(*right))
+            .sum());
+    }
+
+    fn part2((left, right): &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        let right_count = right.iter().fold(HashMap::new(), |mut map, val| {
+            map.entry(*val).and_modify(|n| *n += 1).or_insert(1);
+            map
+        });
+
+        return Ok(left
+            .iter()
+            .filter_map(|left| right_count.get(left).map(|right| left * right))
+            .sum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day1;
+
+    const INPUT: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    utils::examples! {
+        Day1;
+        day1_part1: part1(INPUT) == 11,
+        day1_part2: part2(INPUT) == 31,
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+
+use utils::{AdventOfCode, AocResult, Grid, Point};
+
+/// The topographic map of heights from 0 to 9. Tiles marked `.` are impassable.
+pub struct TrailMap {
+    map: Grid<Option<usize>>,
+}
+
+impl TrailMap {
+    pub fn new(input: &str) -> AocResult<Self> {
+        let map = Grid::parse(input, |c| match c {
+            '.' => Some(None),
+            c => c.to_digit(10).map(|height| Some(height as usize)),
+        })?;
+        Ok(Self { map })
+    }
+
+    /// The positions at height 0 where trails start.
+    pub fn trailheads(&self) -> impl Iterator<Item = Point> + '_ {
+        return self
+            .map
+            .iter()
+            .filter(|(_, height)| **height == Some(0))
+            .map(|(pos, _)| pos);
+    }
+
+    /// The neighbouring positions exactly one step higher than `pos`.
+    fn climbs(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        let next_height = self.map[pos].map(|height| height + 1);
+        return self
+            .map
+            .neighbors(pos)
+            .filter(move |(_, height)| next_height.is_some_and(|next| **height == Some(next)))
+            .map(|(pos, _)| pos);
+    }
+
+    /// The peaks reachable from `pos` by climbing one step at a time.
+    pub fn score(&self, pos: Point) -> HashSet<Point> {
+        match self.map[pos] {
+            Some(9) => HashSet::from([pos]),
+            _ => self.climbs(pos).flat_map(|pos| self.score(pos)).collect(),
+        }
+    }
+
+    /// The number of distinct trails from `pos` to a peak.
+    pub fn rating(&self, pos: Point) -> usize {
+        match self.map[pos] {
+            Some(9) => 1,
+            _ => self.climbs(pos).map(|pos| self.rating(pos)).sum(),
+        }
+    }
+}
+
+pub struct Day10;
+
+impl AdventOfCode for Day10 {
+    type Parsed = TrailMap;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return TrailMap::new(input);
+    }
+
+    fn part1(trails: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(trails.trailheads().map(|pos| trails.score(pos).len()).sum());
+    }
+
+    fn part2(trails: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(trails.trailheads().map(|pos| trails.rating(pos)).sum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day10;
+
+    const INPUT: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    utils::examples! {
+        Day10;
+        day10_part1: part1(INPUT) == 36,
+        day10_part2: part2(INPUT) == 81,
+    }
+}
@@ -0,0 +1,92 @@
+use std::{collections::HashMap, iter::repeat_n};
+
+use utils::{AdventOfCode, AocResult};
+
+/// The stones, counted by the number engraved on them.
+#[derive(Clone)]
+pub struct State {
+    stones: HashMap<usize, usize>,
+}
+
+impl State {
+    pub fn new(input: &str) -> Self {
+        Self {
+            stones: input
+                .split_whitespace()
+                .filter_map(|num| num.parse().ok().map(|num| (num, 1)))
+                .collect(),
+        }
+    }
+
+    /// Applies the engraving rules to every stone once.
+    pub fn blink(&mut self) {
+        let mut new_stones = HashMap::new();
+        for (stone, count) in self.stones.iter() {
+            let stone_str = stone.to_string();
+
+            let stones = match *stone {
+                0 => vec![1],
+                _ if stone_str.len() % 2 == 0 => {
+                    let (left, right) = stone_str.split_at(stone_str.len() / 2);
+                    match utils::parse_tuple(left, right) {
+                        Ok((left, right)) => {
+                            vec![left, right]
+                        }
+                        _ => vec![],
+                    }
+                }
+                stone => vec![stone * 2024],
+            };
+
+            for stone in stones {
+                new_stones
+                    .entry(stone)
+                    .and_modify(|num| *num += *count)
+                    .or_insert(*count);
+            }
+        }
+        self.stones = new_stones;
+    }
+
+    pub fn stone_count(&self) -> usize {
+        return self.stones.values().sum();
+    }
+}
+
+pub struct Day11;
+
+impl AdventOfCode for Day11 {
+    type Parsed = State;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return Ok(State::new(input));
+    }
+
+    fn part1(state: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        let mut state = state.clone();
+        repeat_n((), 25).for_each(|_| state.blink());
+        return Ok(state.stone_count());
+    }
+
+    fn part2(state: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        let mut state = state.clone();
+        repeat_n((), 75).for_each(|_| state.blink());
+        return Ok(state.stone_count());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day11;
+
+    const INPUT: &str = "125 17";
+
+    utils::examples! {
+        Day11;
+        day11_part1: part1(INPUT) == 55312,
+        day11_part2: part2(INPUT) == 65601038650482,
+    }
+}
@@ -0,0 +1,71 @@
+use utils::{AdventOfCode, AocResult, Grid, Region};
+
+/// Splits the garden into regions of adjacent plots growing the same plant.
+pub fn parse_regions(input: &str) -> AocResult<Vec<Region>> {
+    let plots = Grid::chars(input)?;
+    return Ok(utils::connected_components(&plots, |a, b| a == b));
+}
+
+pub struct Day12;
+
+impl AdventOfCode for Day12 {
+    type Parsed = Vec<Region>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return parse_regions(input);
+    }
+
+    fn part1(regions: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(regions
+            .iter()
+            .map(|region| region.area() * region.perimeter())
+            .sum());
+    }
+
+    fn part2(regions: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(regions
+            .iter()
+            .map(|region| region.area() * region.sides())
+            .sum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day12;
+
+    const SMALL: &str = "AAAA
+BBCD
+BBCC
+EEEC";
+
+    const NESTED: &str = "OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+
+    const INPUT: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    utils::examples! {
+        Day12;
+        day12_part1_small: part1(SMALL) == 140,
+        day12_part1_nested: part1(NESTED) == 772,
+        day12_part1: part1(INPUT) == 1930,
+        day12_part2_small: part2(SMALL) == 80,
+        day12_part2_nested: part2(NESTED) == 436,
+        day12_part2: part2(INPUT) == 1206,
+    }
+}
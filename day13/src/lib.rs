@@ -0,0 +1,134 @@
+use utils::{AdventOfCode, AocError, AocResult, Point};
+
+pub struct Day13;
+
+/// A claw machine's button movements and prize location.
+#[derive(Clone, Copy, Debug)]
+pub struct ClawMachine {
+    pub a: Point,
+    pub b: Point,
+    pub p: Point,
+}
+
+impl ClawMachine {
+    pub const PRIZE_OFFSET: isize = 10000000000000;
+
+    /// Parses the three lines describing a machine, numbering them from `first_line`.
+    pub fn new(input: &str, first_line: usize) -> AocResult<Self> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let [a, b, p] = lines[..] else {
+            return Err(AocError::parse("expected two buttons and a prize").at_line(first_line));
+        };
+
+        return Ok(Self {
+            a: parse_line(a, "Button A", first_line)?,
+            b: parse_line(b, "Button B", first_line + 1)?,
+            p: parse_line(p, "Prize", first_line + 2)?,
+        });
+    }
+
+    pub fn with_prize_offset(self, offset: isize) -> Self {
+        return Self {
+            p: self.p + Point::xy(offset),
+            ..self
+        };
+    }
+
+    /// The tokens needed to win the prize, or `None` if it can't be reached.
+    pub fn tokens(&self) -> Option<usize> {
+        let det = cross(self.a, self.b)?;
+        if det == 0 {
+            return None;
+        }
+
+        let a = cross(self.p, self.b)?;
+        let b = cross(self.a, self.p)?;
+
+        if a % det != 0 || b % det != 0 {
+            return None;
+        }
+
+        let (a, b) = (a / det, b / det);
+        if a < 0 || b < 0 {
+            return None;
+        }
+
+        return usize::try_from(a.checked_mul(3)?.checked_add(b)?).ok();
+    }
+}
+
+/// Parses a `key: X+x, Y+y` or `key: X=x, Y=y` line into its coordinates.
+fn parse_line(line: &str, key: &str, line_no: usize) -> AocResult<Point> {
+    let (found, coords) = utils::key_value(line).map_err(|err| err.at_line(line_no))?;
+    if found != key {
+        return Err(
+            AocError::parse(format!("expected {key:?} but found {found:?}")).at_line(line_no),
+        );
+    }
+    let [x, y] = utils::fixed_ints(coords, line_no)?;
+    return Ok(Point::new(x, y));
+}
+
+/// The 2D cross product, or `None` if it overflows.
+fn cross(u: Point, v: Point) -> Option<isize> {
+    return u.x.checked_mul(v.y)?.checked_sub(u.y.checked_mul(v.x)?);
+}
+
+impl AdventOfCode for Day13 {
+    type Parsed = Vec<ClawMachine>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return utils::sections(input)
+            .map(|machine| ClawMachine::new(machine.text, machine.line))
+            .collect();
+    }
+
+    fn part1(machines: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(machines.iter().filter_map(|cm| cm.tokens()).sum());
+    }
+
+    fn part2(machines: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(machines
+            .iter()
+            .filter_map(|cm| cm.with_prize_offset(ClawMachine::PRIZE_OFFSET).tokens())
+            .sum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day13;
+    use utils::AdventOfCode;
+
+    const INPUT: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    utils::examples! {
+        Day13;
+        day13_part1: part1(INPUT) == 480,
+        day13_part2: part2(INPUT) == 875318608908,
+    }
+
+    #[test]
+    fn day13_invalid_prize() {
+        let input = INPUT.replacen("Prize: X=12748", "Prize: X=", 1);
+        let err = Day13::parse(&input).unwrap_err();
+        assert_eq!(err.line, Some(7));
+    }
+}
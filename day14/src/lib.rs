@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use image::{Rgb, RgbImage};
+use utils::{AdventOfCode, AocError, AocResult, Point, PuzzleParams, Rect};
+
+pub struct Params {
+    pub size: Point,
+    /// How many seconds to simulate before part 1 counts the quadrants.
+    pub seconds: usize,
+    /// Save the frame part 2 finds to `day14/output/<second>.png`.
+    pub image: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            size: Point::new(101, 103),
+            seconds: 100,
+            image: false,
+        }
+    }
+}
+
+impl PuzzleParams for Params {
+    fn set(&mut self, key: &str, value: &str) -> AocResult<()> {
+        match key {
+            "size" => self.size = utils::parse_size_param(key, value)?,
+            "seconds" => self.seconds = utils::parse_param(key, value)?,
+            "image" => self.image = utils::parse_param(key, value)?,
+            _ => return Err(utils::unknown_param(key)),
+        }
+        return Ok(());
+    }
+}
+
+/// The robots in the bathroom, which wraps around at `size`.
+pub struct State {
+    robots: Vec<Robot>,
+    size: Point,
+}
+
+impl State {
+    pub fn new(robots: &[Robot], size: Point) -> Self {
+        Self {
+            robots: robots.to_vec(),
+            size,
+        }
+    }
+
+    /// Moves every robot by one second.
+    pub fn tick(&mut self) {
+        self.robots.iter_mut().for_each(|r| r.tick(self.size));
+    }
+
+    /// The number of robots on each occupied tile.
+    pub fn pos_map(&self) -> HashMap<Point, usize> {
+        return self.robots.iter().fold(HashMap::new(), |mut map, r| {
+            map.entry(r.pos)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+            map
+        });
+    }
+
+    /// Saves the current robot positions to `day14/output/<second>.png`.
+    pub fn output_image(&self, second: usize) -> AocResult<()> {
+        let mut image = RgbImage::new(self.size.x as u32, self.size.y as u32);
+        let pos_map = self.pos_map();
+
+        for x in 0..self.size.x {
+            for y in 0..self.size.y {
+                pos_map
+                    .get(&(x, y).into())
+                    .inspect(|_| image.put_pixel(x as u32, y as u32, Rgb([255, 255, 255])));
+            }
+        }
+
+        let mut path = utils::day_dir(14)?;
+        path.push("output");
+        path.push(format!("{second}.png"));
+
+        return image
+            .save(path)
+            .map_err(|err| AocError::solve(format!("failed to save image: {err}")));
+    }
+}
+
+/// A robot's position and velocity per second.
+#[derive(Clone, Copy)]
+pub struct Robot {
+    pub pos: Point,
+    pub vel: Point,
+}
+
+impl Robot {
+    /// Parses a `p=x,y v=dx,dy` line, where `line_no` places any error.
+    pub fn new(line: &str, line_no: usize) -> AocResult<Self> {
+        let [px, py, vx, vy] = utils::fixed_ints(line, line_no)?;
+        return Ok(Self {
+            pos: Point::new(px, py),
+            vel: Point::new(vx, vy),
+        });
+    }
+
+    pub fn tick(&mut self, bounds: Point) {
+        self.pos = (self.pos + self.vel).rem_euclid(bounds);
+    }
+}
+
+pub struct Day14;
+
+impl AdventOfCode for Day14 {
+    type Parsed = Vec<Robot>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Robot::new(line, i + 1))
+            .collect();
+    }
+
+    fn part1(robots: &Self::Parsed, params: &Self::Params) -> AocResult<Self::Part1> {
+        let mut state = State::new(robots, params.size);
+        (0..params.seconds).for_each(|_| state.tick());
+
+        let pos_map = state.pos_map();
+
+        return Ok(Rect::from_size(state.size)
+            .split_quadrants()
+            .iter()
+            .map(|quadrant| {
+                pos_map
+                    .iter()
+                    .filter(|(pos, _)| quadrant.contains(**pos))
+                    .map(|(_, count)| count)
+                    .sum::<usize>()
+            })
+            .product());
+    }
+
+    fn part2(robots: &Self::Parsed, params: &Self::Params) -> AocResult<Self::Part2> {
+        let mut state = State::new(robots, params.size);
+        let period = (state.size.x * state.size.y) as usize;
+
+        // the tree is drawn on the first frame where no two robots overlap
+        for second in 1..=period {
+            state.tick();
+            if state.pos_map().len() == state.robots.len() {
+                if params.image {
+                    state.output_image(second)?;
+                }
+                return Ok(second);
+            }
+        }
+
+        return Err(AocError::solve("robots never stop overlapping"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day14, Params};
+    use utils::{Point, PuzzleParams};
+
+    const PARAMS: Params = Params {
+        size: Point::new(11, 7),
+        seconds: 100,
+        image: false,
+    };
+
+    const INPUT: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    // each row holds a still robot and one moving right that lands on it
+    // after 1, 2 and 3 seconds, so second 4 is the first without overlaps
+    const OVERLAPS: &str = "p=5,1 v=0,0
+p=4,1 v=1,0
+p=5,2 v=0,0
+p=3,2 v=1,0
+p=5,3 v=0,0
+p=2,3 v=1,0";
+
+    utils::examples! {
+        Day14;
+        day14_part1: part1(INPUT, PARAMS) == 12,
+        day14_part2: part2(OVERLAPS, PARAMS) == 4,
+    }
+
+    #[test]
+    fn day14_empty_size() {
+        for size in ["0,0", "7,0", "-1,7"] {
+            let overrides = [("size".to_string(), size.to_string())];
+            assert!(Params::from_overrides(&overrides).is_err(), "{size}");
+        }
+        let overrides = [("size".to_string(), "1,1".to_string())];
+        assert_eq!(
+            Params::from_overrides(&overrides).unwrap().size,
+            Point::xy(1)
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use utils::{AdventOfCode, AocError, AocResult, Direction, Grid, Point};
+
+#[derive(Debug, Clone, Copy)]
+enum BoxSide {
+    Left,
+    Right,
+}
+
+impl BoxSide {
+    fn offset(&self) -> isize {
+        match self {
+            BoxSide::Left => 1,
+            BoxSide::Right => -1,
+        }
+    }
+
+    fn opposite(&self) -> Self {
+        match self {
+            BoxSide::Left => BoxSide::Right,
+            BoxSide::Right => BoxSide::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Wall,
+    Box(BoxSide),
+}
+
+impl Tile {
+    fn new(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Self::Wall),
+            'O' | '[' => Some(Self::Box(BoxSide::Left)),
+            ']' => Some(Self::Box(BoxSide::Right)),
+            _ => None,
+        }
+    }
+}
+
+/// The warehouse map, the boxes in it and the robot's planned moves.
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    tiles: Grid<Option<Tile>>,
+    moves: Vec<Direction>,
+    robot: Point,
+}
+
+impl Warehouse {
+    pub fn new(input: &str) -> AocResult<Self> {
+        let [tiles, moves] = utils::sections(input).exactly()?;
+
+        let robot = Grid::chars(tiles.text)
+            .map_err(|err| tiles.locate(err))?
+            .position(&'@')
+            .ok_or_else(|| AocError::parse("failed to find robot"))?;
+
+        let tiles = Grid::parse(tiles.text, |c| match c {
+            '.' | '@' => Some(None),
+            c => Tile::new(c).map(Some),
+        })
+        .map_err(|err| tiles.locate(err))?;
+
+        let moves = utils::parse_moves(moves.text).map_err(|err| moves.locate(err))?;
+
+        Ok(Self {
+            tiles,
+            moves,
+            robot,
+        })
+    }
+
+    /// Doubles the width of every tile, turning each box into a `[]` pair.
+    pub fn widen(&self) -> Self {
+        let size = Point::new(self.tiles.size().x * 2, self.tiles.size().y);
+        let tiles = Grid::from_fn(size, |pos| {
+            let side = match pos.x % 2 {
+                0 => BoxSide::Left,
+                _ => BoxSide::Right,
+            };
+            self.tile(Point::new(pos.x / 2, pos.y))
+                .map(|tile| match tile {
+                    Tile::Wall => Tile::Wall,
+                    Tile::Box(_) => Tile::Box(side),
+                })
+        });
+
+        Self {
+            tiles,
+            moves: self.moves.clone(),
+            robot: Point::new(self.robot.x * 2, self.robot.y),
+        }
+    }
+
+    fn tile(&self, pos: Point) -> Option<Tile> {
+        return self.tiles.get(pos).copied().flatten();
+    }
+
+    fn can_move(&self, pos: Point, dir: &Direction, expanded: bool) -> bool {
+        let next_pos = pos + dir.offset();
+        // stepping off the map is blocked just like a wall
+        match self.tiles.get(next_pos).copied() {
+            Some(Some(Tile::Box(_))) if !expanded || dir.is_horizontal() => {
+                self.can_move(next_pos, dir, expanded)
+            }
+            Some(Some(Tile::Box(side))) => {
+                self.can_move(next_pos, dir, expanded)
+                    && self.can_move(next_pos.add_x(side.offset()), dir, expanded)
+            }
+            Some(Some(Tile::Wall)) | None => false,
+            Some(None) => true,
+        }
+    }
+
+    fn do_move(&mut self, pos: Point, dir: &Direction, expanded: bool) {
+        let next_pos = pos + dir.offset();
+        match self.tiles[pos].take() {
+            Some(Tile::Box(side)) if !expanded || dir.is_horizontal() => {
+                self.do_move(next_pos, dir, expanded);
+                self.tiles[next_pos] = Some(Tile::Box(side));
+            }
+            Some(Tile::Box(side)) => {
+                self.tiles[pos.add_x(side.offset())] = None;
+                self.do_move(next_pos, dir, expanded);
+                self.do_move(next_pos.add_x(side.offset()), dir, expanded);
+                self.tiles[next_pos] = Some(Tile::Box(side));
+                self.tiles[next_pos.add_x(side.offset())] = Some(Tile::Box(side.opposite()));
+            }
+            _ => {}
+        }
+    }
+
+    /// Performs every move, pushing boxes that aren't blocked by a wall.
+    pub fn execute(&mut self, expanded: bool) {
+        let moves = self.moves.clone();
+        for dir in moves {
+            if self.can_move(self.robot, &dir, expanded) {
+                self.do_move(self.robot + dir.offset(), &dir, expanded);
+                self.robot += dir.offset();
+            }
+        }
+    }
+
+    /// Sums the GPS coordinates of every box.
+    pub fn gps_coords(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter_map(|(pos, tile)| match tile {
+                Some(Tile::Box(BoxSide::Left)) => Some((pos.x + pos.y * 100) as usize),
+                _ => None,
+            })
+            .sum()
+    }
+}
+
+pub struct Day15;
+
+impl AdventOfCode for Day15 {
+    type Parsed = Warehouse;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return Warehouse::new(input);
+    }
+
+    fn part1(warehouse: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        let mut warehouse = warehouse.clone();
+        warehouse.execute(false);
+        return Ok(warehouse.gps_coords());
+    }
+
+    fn part2(warehouse: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        let mut warehouse = warehouse.widen();
+        warehouse.execute(true);
+        return Ok(warehouse.gps_coords());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day15;
+    use utils::AdventOfCode;
+
+    const INPUT: &str = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    utils::examples! {
+        Day15;
+        day15_part1: part1(INPUT) == 10092,
+        day15_part2: part2(INPUT) == 9021,
+        day15_part1_edge: part1("@.O\n\n<<>>>>") == 2,
+        day15_part2_edge: part2("@O\n.O\n\n>v>>") == 104,
+    }
+
+    #[test]
+    fn day15_invalid_move() {
+        let err = Day15::parse("#####\n#@.O#\n#####\n\n<>\n^x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(6), Some(2)));
+    }
+}
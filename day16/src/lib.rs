@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use utils::{AdventOfCode, AocError, AocResult, Direction, Grid, Point};
+
+/// The reindeer maze, with the reindeer starting on `S` facing east.
+pub struct Maze {
+    walls: Grid<bool>,
+    reindeer: (Point, Direction),
+    end: Point,
+}
+
+/// The lowest score from start to end and the tiles on every path with that score.
+pub struct BestPaths {
+    pub score: usize,
+    pub tiles: usize,
+}
+
+impl Maze {
+    pub fn new(input: &str) -> AocResult<Self> {
+        let chars = Grid::chars(input)?;
+
+        let start = chars
+            .position(&'S')
+            .ok_or_else(|| AocError::parse("failed to find start tile"))?;
+
+        let end = chars
+            .position(&'E')
+            .ok_or_else(|| AocError::parse("failed to find end tile"))?;
+
+        let walls = chars.map(|c| *c == '#');
+
+        Ok(Self {
+            walls,
+            reindeer: (start, Direction::East),
+            end,
+        })
+    }
+
+    /// Whether `pos` is a wall, treating everything outside the map as one.
+    fn is_wall(&self, pos: Point) -> bool {
+        return self.walls.get(pos).copied().unwrap_or(true);
+    }
+
+    /// The lowest score and the tiles on every path with it, which both parts share.
+    pub fn best_paths(&self) -> AocResult<BestPaths> {
+        return self
+            .all_paths()
+            .ok_or_else(|| AocError::solve("no path from start to end"));
+    }
+
+    /// The moves open to the reindeer: a step forward, or a turn towards an open tile.
+    fn moves(&self, (pos, dir): (Point, Direction)) -> Vec<((Point, Direction), usize)> {
+        let mut moves = Vec::with_capacity(3);
+        if !self.is_wall(pos + dir.offset()) {
+            moves.push(((pos + dir.offset(), dir), 1));
+        }
+        for turned in [dir.turn_left(), dir.turn_right()] {
+            if !self.is_wall(pos + turned.offset()) {
+                moves.push(((pos, turned), 1000));
+            }
+        }
+        return moves;
+    }
+
+    fn all_paths(&self) -> Option<BestPaths> {
+        let paths = utils::dijkstra_all(self.reindeer, |&state| self.moves(state));
+
+        let ends = Direction::all().map(|dir| (self.end, dir));
+        let (_, score) = paths.cheapest(&ends)?;
+        let best_ends = ends
+            .into_iter()
+            .filter(|end| paths.cost(end) == Some(score));
+
+        let tiles = paths
+            .states_on_paths(best_ends)
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect::<HashSet<_>>();
+
+        return Some(BestPaths {
+            score,
+            tiles: tiles.len(),
+        });
+    }
+}
+
+pub struct Day16;
+
+impl AdventOfCode for Day16 {
+    /// The search is done while parsing so its cost isn't hidden in whichever
+    /// part runs first.
+    type Parsed = BestPaths;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return Maze::new(input)?.best_paths();
+    }
+
+    fn part1(best: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(best.score);
+    }
+
+    fn part2(best: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(best.tiles);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day16;
+
+    const INPUT: &str = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const LARGE: &str = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    utils::examples! {
+        Day16;
+        day16_part1: part1(INPUT) == 7036,
+        day16_part1_large: part1(LARGE) == 11048,
+        day16_part2: part2(INPUT) == 45,
+        day16_part2_large: part2(LARGE) == 64,
+    }
+}
@@ -0,0 +1,922 @@
+use std::fmt::Display;
+
+use utils::{AdventOfCode, AocError, AocResult};
+
+/// A decoded instruction with its raw operand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Adv(usize),
+    Bxl(usize),
+    Bst(usize),
+    Jnz(usize),
+    /// Reads no operand, but keeps it so the program can be rebuilt.
+    Bxc(usize),
+    Out(usize),
+    Bdv(usize),
+    Cdv(usize),
+}
+
+/// How an instruction reads its operand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    /// The operand's own value.
+    Literal(usize),
+    /// `0` to `3` as themselves, `4` to `6` as registers `A` to `C`.
+    Combo(usize),
+    Ignored(usize),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Operand::Literal(value) | Operand::Combo(value @ 0..4) => write!(f, "{value}"),
+            Operand::Combo(4) => write!(f, "A"),
+            Operand::Combo(5) => write!(f, "B"),
+            Operand::Combo(6) => write!(f, "C"),
+            Operand::Combo(value) => write!(f, "<reserved {value}>"),
+            Operand::Ignored(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl Instruction {
+    /// Decodes an opcode and its operand, or `None` if the opcode isn't `0` to `7`.
+    pub fn new(num: usize, operand: usize) -> Option<Self> {
+        let ins = match num {
+            0 => Self::Adv(operand),
+            1 => Self::Bxl(operand),
+            2 => Self::Bst(operand),
+            3 => Self::Jnz(operand),
+            4 => Self::Bxc(operand),
+            5 => Self::Out(operand),
+            6 => Self::Bdv(operand),
+            7 => Self::Cdv(operand),
+            _ => return None,
+        };
+        return Some(ins);
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Self::Adv(_) => "adv",
+            Self::Bxl(_) => "bxl",
+            Self::Bst(_) => "bst",
+            Self::Jnz(_) => "jnz",
+            Self::Bxc(_) => "bxc",
+            Self::Out(_) => "out",
+            Self::Bdv(_) => "bdv",
+            Self::Cdv(_) => "cdv",
+        }
+    }
+
+    pub fn operand(&self) -> Operand {
+        match *self {
+            Self::Bxl(op) | Self::Jnz(op) => Operand::Literal(op),
+            Self::Bxc(op) => Operand::Ignored(op),
+            Self::Adv(op) | Self::Bst(op) | Self::Out(op) | Self::Bdv(op) | Self::Cdv(op) => {
+                Operand::Combo(op)
+            }
+        }
+    }
+
+    pub fn opcode(&self) -> usize {
+        match self {
+            Self::Adv(_) => 0,
+            Self::Bxl(_) => 1,
+            Self::Bst(_) => 2,
+            Self::Jnz(_) => 3,
+            Self::Bxc(_) => 4,
+            Self::Out(_) => 5,
+            Self::Bdv(_) => 6,
+            Self::Cdv(_) => 7,
+        }
+    }
+
+    /// The opcode and operand as they appear in the program.
+    pub fn encode(&self) -> [usize; 2] {
+        let (Operand::Literal(op) | Operand::Combo(op) | Operand::Ignored(op)) = self.operand();
+        return [self.opcode(), op];
+    }
+
+    /// What the instruction does, written out with its operand resolved.
+    pub fn describe(&self) -> String {
+        let op = self.operand();
+        match self {
+            Self::Adv(_) => format!("A = A >> {op}"),
+            Self::Bxl(_) => format!("B = B ^ {op}"),
+            Self::Bst(_) => format!("B = {op} % 8"),
+            Self::Jnz(_) => format!("jump to {op} if A != 0"),
+            Self::Bxc(_) => "B = B ^ C".to_string(),
+            Self::Out(_) => format!("output {op} % 8"),
+            Self::Bdv(_) => format!("B = A >> {op}"),
+            Self::Cdv(_) => format!("C = A >> {op}"),
+        }
+    }
+}
+
+/// Renders as assembly, such as `bst A`, leaving out a `bxc` operand of zero.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.operand() {
+            Operand::Ignored(0) => write!(f, "{}", self.mnemonic()),
+            op => write!(f, "{} {op}", self.mnemonic()),
+        }
+    }
+}
+
+/// An annotated listing of a program, one instruction per line with its
+/// address and what it does. Addresses that a `jnz` jumps to are marked
+/// with `>`.
+pub fn disassemble(instructions: &[usize]) -> String {
+    let targets = instructions
+        .chunks(2)
+        .filter(|pair| pair[0] == 3 && pair.len() == 2)
+        .map(|pair| pair[1])
+        .collect::<Vec<_>>();
+
+    let mut lines = Vec::new();
+    for (i, pair) in instructions.chunks(2).enumerate() {
+        let addr = i * 2;
+        let marker = if targets.contains(&addr) { '>' } else { ' ' };
+
+        let (text, note) = match *pair {
+            [opcode, operand] => match Instruction::new(opcode, operand) {
+                Some(ins) => {
+                    let mut note = ins.describe();
+                    if let Instruction::Jnz(target) = ins {
+                        if target % 2 != 0 {
+                            note.push_str(" (mid-instruction)");
+                        } else if target >= instructions.len() {
+                            note.push_str(" (halts)");
+                        }
+                    }
+                    (ins.to_string(), note)
+                }
+                None => (format!("{opcode}"), "invalid opcode".to_string()),
+            },
+            [opcode] => (format!("{opcode}"), "missing operand".to_string()),
+            _ => unreachable!("chunks are one or two values long"),
+        };
+        lines.push(format!("{marker}{addr:>3}: {text:<8} ; {note}"));
+    }
+    return lines.join("\n");
+}
+
+/// Assembles a program written one instruction per line, such as `bst A`
+/// or `jnz 0`. Combo operands are `0` to `3` or a register, literal operands
+/// are `0` to `7`, and `bxc` may leave its operand out. Blank lines and
+/// anything after a `;` are skipped, as are the markers and addresses that
+/// `disassemble` adds, so a listing assembles back into its program.
+pub fn assemble(source: &str) -> AocResult<Vec<usize>> {
+    let mut instructions = Vec::new();
+
+    for (i, line) in source.lines().enumerate() {
+        let code = line.split(';').next().unwrap_or_default();
+        let code = code.trim().trim_start_matches('>');
+        let code = match code.split_once(':') {
+            Some((addr, rest)) if addr.trim().parse::<usize>().is_ok() => rest,
+            _ => code,
+        };
+
+        let mut words = code.split_whitespace();
+        let Some(name) = words.next() else {
+            continue;
+        };
+        let operand = words.next();
+        if let Some(extra) = words.next() {
+            return Err(AocError::parse(format!("unexpected {extra:?}")).at_line(i + 1));
+        }
+
+        let pair = parse_instruction(name, operand).map_err(|err| err.at_line(i + 1))?;
+        instructions.extend(pair);
+    }
+
+    return Ok(instructions);
+}
+
+/// The opcode and operand for one line of assembly.
+fn parse_instruction(name: &str, operand: Option<&str>) -> AocResult<[usize; 2]> {
+    let ins = (0..8)
+        .filter_map(|opcode| Instruction::new(opcode, 0))
+        .find(|ins| ins.mnemonic() == name.to_lowercase())
+        .ok_or_else(|| AocError::parse(format!("unknown mnemonic {name:?}")))?;
+
+    let value = match (ins.operand(), operand) {
+        (Operand::Ignored(_), None) => 0,
+        (_, None) => return Err(AocError::parse(format!("{name} needs an operand"))),
+        (Operand::Combo(_), Some("A" | "a")) => 4,
+        (Operand::Combo(_), Some("B" | "b")) => 5,
+        (Operand::Combo(_), Some("C" | "c")) => 6,
+        (kind, Some(operand)) => {
+            let (max, expected) = match kind {
+                Operand::Combo(_) => (3, "0 to 3 or a register"),
+                _ => (7, "0 to 7"),
+            };
+            operand
+                .parse()
+                .ok()
+                .filter(|value| *value <= max)
+                .ok_or_else(|| {
+                    AocError::parse(format!(
+                        "operand {operand:?} of {name} is out of range, expected {expected}"
+                    ))
+                })?
+        }
+    };
+
+    return Ok([ins.opcode(), value]);
+}
+
+/// The 3-bit computer's registers, program counter and program.
+#[derive(Clone)]
+pub struct CPU {
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+    pub pc: usize,
+    pub instructions: Vec<usize>,
+}
+
+impl CPU {
+    /// How many instructions a quine candidate may run before it is assumed
+    /// to loop forever.
+    const QUINE_STEP_LIMIT: usize = 100_000;
+    /// The values of register A tried for programs the digit search can't
+    /// handle, and the steps each may run.
+    const QUINE_SCAN_LIMIT: usize = 1 << 16;
+    const QUINE_SCAN_STEPS: usize = 10_000;
+
+    pub fn new(input: &str) -> AocResult<Self> {
+        let [registers, program] = utils::sections(input).exactly()?;
+
+        let registers = registers
+            .lines()
+            .enumerate()
+            .map(|(i, (line_no, line))| {
+                let name = format!("Register {}", (b'A' + i as u8) as char);
+                utils::parse_value(line, &name).map_err(|err| err.at_line(line_no))
+            })
+            .collect::<AocResult<Vec<usize>>>()?;
+
+        let [a, b, c] = registers[..] else {
+            return Err(AocError::parse("expected three registers"));
+        };
+
+        let line = program.line;
+        let (key, program) = utils::key_value(program.text).map_err(|err| err.at_line(line))?;
+        if key != "Program" {
+            return Err(AocError::parse("expected a program").at_line(line));
+        }
+        // opcodes and operands are checked as they run, so `step` reports them
+        let instructions = program
+            .split(",")
+            .map(|num| {
+                num.trim().parse().map_err(|_| {
+                    AocError::parse(format!("invalid program value {num:?}")).at_line(line)
+                })
+            })
+            .collect::<AocResult<Vec<_>>>()?;
+
+        Ok(Self {
+            a,
+            b,
+            c,
+            pc: 0,
+            instructions,
+        })
+    }
+
+    /// The program as an annotated listing.
+    pub fn disassemble(&self) -> String {
+        return disassemble(&self.instructions);
+    }
+
+    /// A computer running `instructions` with only register A set.
+    pub fn seeded(a: usize, instructions: Vec<usize>) -> Self {
+        Self {
+            a,
+            b: 0,
+            c: 0,
+            pc: 0,
+            instructions,
+        }
+    }
+
+    fn combo(&self, operand: usize) -> Result<usize, VmError> {
+        match operand {
+            0..4 => Ok(operand),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err(VmError::InvalidOperand {
+                pc: self.pc,
+                operand,
+            }),
+        }
+    }
+
+    /// Register A divided by two to the power of a combo operand.
+    fn divide(&self, operand: usize) -> Result<usize, VmError> {
+        let shift = u32::try_from(self.combo(operand)?).unwrap_or(u32::MAX);
+        return Ok(self.a.checked_shr(shift).unwrap_or(0));
+    }
+
+    /// The registers and program counter as they are now.
+    pub fn registers(&self) -> Registers {
+        Registers {
+            a: self.a,
+            b: self.b,
+            c: self.c,
+            pc: self.pc,
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.instructions.len()
+    }
+
+    /// Executes the instruction at the program counter, or returns `None` if
+    /// the program has halted. On an error the computer is left unchanged.
+    pub fn step(&mut self) -> Result<Option<Step>, VmError> {
+        if self.is_halted() {
+            return Ok(None);
+        }
+
+        let addr = self.pc;
+        let opcode = self.instructions[addr];
+        let operand = *self
+            .instructions
+            .get(addr + 1)
+            .ok_or(VmError::MissingOperand { pc: addr, opcode })?;
+        let ins =
+            Instruction::new(opcode, operand).ok_or(VmError::InvalidOpcode { pc: addr, opcode })?;
+        let mut output = None;
+
+        match ins {
+            Instruction::Adv(op) => self.a = self.divide(op)?,
+            Instruction::Bxl(op) => self.b ^= op,
+            Instruction::Bst(op) => self.b = self.combo(op)? % 8,
+            Instruction::Jnz(op) if self.a > 0 => self.pc = op,
+            Instruction::Jnz(_) => self.pc += 2,
+            Instruction::Bxc(_) => self.b ^= self.c,
+            Instruction::Out(op) => output = Some(self.combo(op)? % 8),
+            Instruction::Bdv(op) => self.b = self.divide(op)?,
+            Instruction::Cdv(op) => self.c = self.divide(op)?,
+        }
+
+        if !matches!(ins, Instruction::Jnz(_)) {
+            self.pc += 2;
+        }
+
+        return Ok(Some(Step {
+            addr,
+            instruction: ins,
+            output,
+            registers: self.registers(),
+        }));
+    }
+
+    /// Every step until the program halts or fails, ending after the error.
+    /// The iterator never ends if the program doesn't, so bound it with `take`
+    /// or use a `Debugger`.
+    pub fn trace(&mut self) -> impl Iterator<Item = Result<Step, VmError>> + '_ {
+        let mut failed = false;
+        return std::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+            let step = self.step().transpose();
+            failed = matches!(step, Some(Err(_)));
+            return step;
+        });
+    }
+
+    /// Runs the program until it halts, returning its output.
+    pub fn run(&mut self) -> Result<Vec<usize>, VmError> {
+        let mut output = Vec::new();
+        for step in self.trace() {
+            output.extend(step?.output);
+        }
+        return Ok(output);
+    }
+
+    /// Runs the program like `run`, but gives up after `max_steps`
+    /// instructions instead of looping forever.
+    pub fn run_limited(&mut self, max_steps: usize) -> AocResult<Vec<usize>> {
+        let mut output = Vec::new();
+        for _ in 0..max_steps {
+            match self.step()? {
+                Some(step) => output.extend(step.output),
+                None => return Ok(output),
+            }
+        }
+        if !self.is_halted() {
+            return Err(AocError::solve(format!(
+                "program still running after {max_steps} steps, it probably loops forever"
+            )));
+        }
+        return Ok(output);
+    }
+
+    /// Finds the lowest value of register A that makes the program output itself.
+    pub fn find_quine(&self) -> AocResult<usize> {
+        return self.find_quines().first().copied().ok_or_else(|| {
+            let message = match self.quine_shift() {
+                Some(_) => "no value of register A makes the program output itself".to_string(),
+                None => format!(
+                    "no value of register A up to {} makes the program output itself",
+                    Self::QUINE_SCAN_LIMIT
+                ),
+            };
+            return AocError::solve(message);
+        });
+    }
+
+    /// Every value of register A that makes the program output itself, from
+    /// lowest to highest.
+    ///
+    /// Programs shaped like the puzzle's are searched exhaustively: one loop
+    /// ending in its only jump, `jnz 0`, that outputs once and runs `adv` with
+    /// a literal `1` to `3` once per pass, setting B and C from A before
+    /// reading them. Each pass then depends only on A, so the last outputs only
+    /// depend on the highest bits of A. A is built from the top down, trying
+    /// every value for the bits the next pass shifts out and backtracking
+    /// whenever a choice can't produce the rest of the program.
+    ///
+    /// Any other program is only tried with A up to `QUINE_SCAN_LIMIT`, each
+    /// run limited to `QUINE_SCAN_STEPS` instructions.
+    pub fn find_quines(&self) -> Vec<usize> {
+        let mut cpu = self.clone();
+        let Some(shift) = self.quine_shift() else {
+            return (1..=Self::QUINE_SCAN_LIMIT)
+                .filter(|&a| {
+                    cpu.reset(self, a);
+                    return cpu
+                        .run_limited(Self::QUINE_SCAN_STEPS)
+                        .is_ok_and(|output| output == self.instructions);
+                })
+                .collect();
+        };
+
+        let mut quines = Vec::new();
+        if let Some(last) = self.instructions.len().checked_sub(1) {
+            self.search_quines(&mut cpu, shift, last, 0, &mut quines);
+        }
+        quines.sort_unstable();
+        quines.dedup();
+        return quines;
+    }
+
+    /// How many bits of A each pass of the program shifts out, if it has the
+    /// shape `find_quines` can search digit by digit.
+    fn quine_shift(&self) -> Option<u32> {
+        let program = self
+            .instructions
+            .chunks(2)
+            .map(|pair| match *pair {
+                [opcode, operand] => Instruction::new(opcode, operand),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let shifts = program
+            .iter()
+            .filter_map(|ins| match ins {
+                Instruction::Adv(op) => Some(*op),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let [shift @ 1..=3] = shifts[..] else {
+            return None;
+        };
+
+        let count =
+            |wanted: fn(&Instruction) -> bool| program.iter().filter(|ins| wanted(ins)).count();
+        if program.last() != Some(&Instruction::Jnz(0))
+            || count(|ins| matches!(ins, Instruction::Jnz(_))) != 1
+            || count(|ins| matches!(ins, Instruction::Out(_))) != 1
+        {
+            return None;
+        }
+
+        // B and C must not carry over from the previous pass
+        let combo = |op: usize| match op {
+            5 => vec!['B'],
+            6 => vec!['C'],
+            _ => vec![],
+        };
+        let mut written = Vec::new();
+        for ins in &program {
+            let (reads, writes) = match *ins {
+                Instruction::Adv(op) | Instruction::Out(op) => (combo(op), None),
+                Instruction::Bxl(_) => (vec!['B'], Some('B')),
+                Instruction::Bst(op) | Instruction::Bdv(op) => (combo(op), Some('B')),
+                Instruction::Bxc(_) => (vec!['B', 'C'], Some('B')),
+                Instruction::Cdv(op) => (combo(op), Some('C')),
+                Instruction::Jnz(_) => (vec![], None),
+            };
+            if reads.iter().any(|reg| !written.contains(reg)) {
+                return None;
+            }
+            written.extend(writes);
+        }
+
+        return Some(shift as u32);
+    }
+
+    /// Restarts the program from the top with `original`'s registers, except
+    /// that A is `a`.
+    fn reset(&mut self, original: &CPU, a: usize) {
+        self.a = a;
+        self.b = original.b;
+        self.c = original.c;
+        self.pc = 0;
+    }
+
+    /// Extends `prefix` with each possible value of the next `shift` bits of A
+    /// that make the program output `instructions[index..]`, reusing `cpu` for
+    /// every run.
+    fn search_quines(
+        &self,
+        cpu: &mut CPU,
+        shift: u32,
+        index: usize,
+        prefix: usize,
+        quines: &mut Vec<usize>,
+    ) {
+        let Some(base) = prefix.checked_mul(1 << shift) else {
+            return;
+        };
+
+        for bits in 0..1 << shift {
+            let a = base | bits;
+            // A of zero only repeats the search from the same state
+            if a == 0 {
+                continue;
+            }
+
+            cpu.reset(self, a);
+            let matches = cpu
+                .run_limited(Self::QUINE_STEP_LIMIT)
+                .is_ok_and(|output| output == self.instructions[index..]);
+
+            match (matches, index) {
+                (false, _) => {}
+                (true, 0) => quines.push(a),
+                (true, index) => self.search_quines(cpu, shift, index - 1, a, quines),
+            }
+        }
+    }
+}
+
+/// Why the computer couldn't execute the instruction at `pc`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VmError {
+    /// The opcode isn't `0` to `7`.
+    InvalidOpcode { pc: usize, opcode: usize },
+    /// A combo operand of `7`, which is reserved, or above.
+    InvalidOperand { pc: usize, operand: usize },
+    /// The program ends after the opcode, before its operand.
+    MissingOperand { pc: usize, opcode: usize },
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VmError::InvalidOpcode { pc, opcode } => {
+                write!(f, "invalid opcode {opcode} at pc {pc}")
+            }
+            VmError::InvalidOperand { pc, operand } => {
+                write!(f, "invalid combo operand {operand} at pc {pc}")
+            }
+            VmError::MissingOperand { pc, opcode } => {
+                write!(f, "opcode {opcode} at pc {pc} is missing its operand")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+impl From<VmError> for AocError {
+    fn from(err: VmError) -> Self {
+        AocError::solve(err.to_string())
+    }
+}
+
+/// The registers and program counter at one point of a run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registers {
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+    pub pc: usize,
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "A={} B={} C={} pc={}", self.a, self.b, self.c, self.pc)
+    }
+}
+
+/// One executed instruction and the state it left behind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// Where the instruction was.
+    pub addr: usize,
+    pub instruction: Instruction,
+    pub output: Option<usize>,
+    pub registers: Registers,
+}
+
+/// Where a `Debugger` pauses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Before executing the instruction at this address.
+    Pc(usize),
+    /// Once the program has output this many values.
+    Outputs(usize),
+}
+
+/// Why a `Debugger` stopped running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    Breakpoint(Breakpoint),
+    /// The step limit was reached, which usually means an infinite loop.
+    StepLimit,
+    Error(VmError),
+}
+
+/// Runs a `CPU` under control, pausing on breakpoints and giving up after a
+/// maximum number of steps.
+pub struct Debugger {
+    pub cpu: CPU,
+    /// Every value output so far.
+    pub output: Vec<usize>,
+    /// The number of instructions executed so far.
+    pub steps: usize,
+    breakpoints: Vec<Breakpoint>,
+    max_steps: Option<usize>,
+}
+
+impl Debugger {
+    pub fn new(cpu: CPU) -> Self {
+        Self {
+            cpu,
+            output: Vec::new(),
+            steps: 0,
+            breakpoints: Vec::new(),
+            max_steps: None,
+        }
+    }
+
+    pub fn with_breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.breakpoints.push(breakpoint);
+        return self;
+    }
+
+    pub fn with_max_steps(self, max_steps: usize) -> Self {
+        return Self {
+            max_steps: Some(max_steps),
+            ..self
+        };
+    }
+
+    /// Executes one instruction, recording its output.
+    pub fn step(&mut self) -> Result<Option<Step>, VmError> {
+        let Some(step) = self.cpu.step()? else {
+            return Ok(None);
+        };
+        self.steps += 1;
+        self.output.extend(step.output);
+        return Ok(Some(step));
+    }
+
+    /// Runs until the program halts, a breakpoint is hit or the step limit is
+    /// reached. At least one instruction runs, so resuming from a breakpoint
+    /// moves past it.
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            if self.max_steps.is_some_and(|max| self.steps >= max) && !self.cpu.is_halted() {
+                return Stop::StepLimit;
+            }
+            let step = match self.step() {
+                Ok(Some(step)) => step,
+                Ok(None) => return Stop::Halted,
+                Err(err) => return Stop::Error(err),
+            };
+
+            let hit = self.breakpoints.iter().find(|bp| match bp {
+                Breakpoint::Pc(pc) => self.cpu.pc == *pc,
+                Breakpoint::Outputs(count) => step.output.is_some() && self.output.len() == *count,
+            });
+            if let Some(&breakpoint) = hit {
+                return Stop::Breakpoint(breakpoint);
+            }
+        }
+    }
+}
+
+pub struct Day17;
+
+impl AdventOfCode for Day17 {
+    type Parsed = CPU;
+    type Part1 = String;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return CPU::new(input);
+    }
+
+    fn part1(cpu: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(cpu
+            .clone()
+            .run()?
+            .iter()
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>()
+            .join(","));
+    }
+
+    fn part2(cpu: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return cpu.find_quine();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Breakpoint, Day17, Debugger, Stop, VmError, CPU};
+
+    const PART1: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    const INPUT: &str = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+    utils::examples! {
+        Day17;
+        day17_part1: part1(PART1) == "4,6,3,5,6,3,5,2,1,0",
+        day17_part1_quine: part1(INPUT) == "5,7,3,0",
+        day17_part2: part2(INPUT) == 117440,
+    }
+
+    #[test]
+    fn day17_debugger() {
+        let mut debugger = Debugger::new(CPU::new(PART1).unwrap())
+            .with_breakpoint(Breakpoint::Outputs(3))
+            .with_breakpoint(Breakpoint::Pc(4));
+
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Pc(4)));
+        assert_eq!(debugger.output, [4]);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Pc(4)));
+        assert_eq!(debugger.output, [4, 6]);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Outputs(3)));
+        assert_eq!(debugger.cpu.registers().a, 729 >> 3);
+        assert_eq!(debugger.steps, 8);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(Breakpoint::Pc(4)));
+        assert_eq!(debugger.output, [4, 6, 3, 5]);
+    }
+
+    #[test]
+    fn day17_step_limit() {
+        let mut cpu = CPU::seeded(1, vec![3, 0]);
+        assert!(cpu.run_limited(1000).is_err());
+        assert_eq!(
+            CPU::seeded(729, vec![0, 1, 5, 4, 3, 0])
+                .run_limited(1000)
+                .unwrap()
+                .len(),
+            10
+        );
+    }
+
+    #[test]
+    fn day17_quines() {
+        let quines = CPU::new(INPUT).unwrap().find_quines();
+        assert_eq!(quines.first(), Some(&117440));
+        assert!(quines
+            .iter()
+            .all(|&a| CPU::seeded(a, vec![0, 3, 5, 4, 3, 0]).run() == Ok(vec![0, 3, 5, 4, 3, 0])));
+    }
+
+    #[test]
+    fn day17_no_quine() {
+        let cpu = CPU::seeded(0, vec![0, 3, 5, 0, 3, 0]);
+        assert_eq!(
+            cpu.find_quine().unwrap_err().message,
+            "no value of register A makes the program output itself"
+        );
+    }
+
+    #[test]
+    fn day17_quine_shape() {
+        let shift = |program| CPU::seeded(0, program).quine_shift();
+        assert_eq!(shift(vec![0, 3, 5, 4, 3, 0]), Some(3));
+        assert_eq!(shift(vec![0, 1, 5, 4, 3, 0]), Some(1));
+        assert_eq!(
+            shift(vec![2, 4, 1, 1, 7, 5, 0, 2, 4, 0, 5, 5, 3, 0]),
+            Some(2)
+        );
+        assert_eq!(shift(vec![0, 4, 5, 4, 3, 0]), None);
+        assert_eq!(shift(vec![0, 3, 3, 0]), None);
+        assert_eq!(shift(vec![0, 3, 5, 4, 3, 2]), None);
+        assert_eq!(shift(vec![0, 3, 5, 5, 3, 0]), None);
+    }
+
+    #[test]
+    fn day17_quine_scan() {
+        // the digit search and a plain scan agree on the example
+        let cpu = CPU::new(INPUT).unwrap();
+        let scanned = (1..=1 << 17)
+            .filter(|&a| CPU { a, ..cpu.clone() }.run() == Ok(cpu.instructions.clone()))
+            .collect::<Vec<_>>();
+        let searched = cpu.find_quines();
+        assert_eq!(scanned, searched[..scanned.len()]);
+
+        // other programs are only scanned up to a bound
+        let err = CPU::seeded(0, vec![0, 3, 3, 0]).find_quine().unwrap_err();
+        assert_eq!(
+            err.message,
+            "no value of register A up to 65536 makes the program output itself"
+        );
+    }
+
+    #[test]
+    fn day17_assemble() {
+        let source = "bst A\nbxl 5 ; flip\n\ncdv B\nbxc\nout b\nadv 3\njnz 0";
+        let program = vec![2, 4, 1, 5, 7, 5, 4, 0, 5, 5, 0, 3, 3, 0];
+        assert_eq!(crate::assemble(source).unwrap(), program);
+        assert_eq!(
+            crate::assemble(&crate::disassemble(&program)).unwrap(),
+            program
+        );
+    }
+
+    #[test]
+    fn day17_assemble_errors() {
+        let err = crate::assemble("bst A\nmul 3").unwrap_err();
+        assert_eq!(err.line, Some(2));
+        assert!(crate::assemble("out 4").is_err());
+        assert!(crate::assemble("bxl 8").is_err());
+        assert!(crate::assemble("jnz").is_err());
+    }
+
+    #[test]
+    fn day17_vm_errors() {
+        let run = |instructions: Vec<usize>| CPU::seeded(1, instructions).run().unwrap_err();
+        assert_eq!(
+            run(vec![2, 4, 8, 0]),
+            VmError::InvalidOpcode { pc: 2, opcode: 8 }
+        );
+        assert_eq!(
+            run(vec![5, 7]),
+            VmError::InvalidOperand { pc: 0, operand: 7 }
+        );
+        assert_eq!(
+            run(vec![1, 2, 5]),
+            VmError::MissingOperand { pc: 2, opcode: 5 }
+        );
+
+        let parsed = |program| {
+            let input =
+                format!("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: {program}");
+            return CPU::new(&input).unwrap().run().unwrap_err();
+        };
+        assert_eq!(
+            parsed("2,4,8,0"),
+            VmError::InvalidOpcode { pc: 2, opcode: 8 }
+        );
+        assert_eq!(
+            parsed("1,2,5"),
+            VmError::MissingOperand { pc: 2, opcode: 5 }
+        );
+        assert!(CPU::new("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,x").is_err());
+    }
+
+    #[test]
+    fn day17_disassemble() {
+        let listing = crate::disassemble(&[2, 4, 1, 5, 4, 3, 5, 5, 3, 0]);
+        let expected = [
+            ">  0: bst A    ; B = A % 8",
+            "   2: bxl 5    ; B = B ^ 5",
+            "   4: bxc 3    ; B = B ^ C",
+            "   6: out B    ; output B % 8",
+            "   8: jnz 0    ; jump to 0 if A != 0",
+        ];
+        assert_eq!(listing, expected.join("\n"));
+
+        let listing = crate::disassemble(&[8, 1, 5]);
+        assert_eq!(
+            listing,
+            "   0: 8        ; invalid opcode\n   2: 5        ; missing operand"
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use utils::{AdventOfCode, AocError, AocResult, Direction, Point, PuzzleParams, Rect};
+
+pub struct Params {
+    /// The width and height of the memory space. The exit is its bottom right corner.
+    pub size: Point,
+    /// How many bytes have fallen before part 1 looks for a path.
+    pub bytes: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            size: Point::new(71, 71),
+            bytes: 1024,
+        }
+    }
+}
+
+impl PuzzleParams for Params {
+    fn set(&mut self, key: &str, value: &str) -> AocResult<()> {
+        match key {
+            "size" => self.size = utils::parse_size_param(key, value)?,
+            "bytes" => self.bytes = utils::parse_param(key, value)?,
+            _ => return Err(utils::unknown_param(key)),
+        }
+        return Ok(());
+    }
+}
+
+/// The memory grid and the bytes falling into it, in order.
+pub struct MemorySpace<'a> {
+    incoming: &'a [Point],
+    exit: Point,
+    bounds: Rect,
+}
+
+impl<'a> MemorySpace<'a> {
+    pub fn new(incoming: &'a [Point], size: Point) -> Self {
+        Self {
+            incoming,
+            exit: size - Point::xy(1),
+            bounds: Rect::from_size(size),
+        }
+    }
+
+    /// The fewest steps to the exit once the first `bytes` bytes have fallen.
+    pub fn shortest_path(&self, bytes: usize) -> Option<usize> {
+        return utils::astar(
+            Point::ZERO,
+            |&pos| {
+                Direction::all()
+                    .iter()
+                    .map(|dir| pos + dir.offset())
+                    .filter(|pos| {
+                        self.bounds.contains(*pos) && !self.incoming[..bytes].contains(pos)
+                    })
+                    .map(|pos| (pos, 1))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| {
+                let diff = self.exit - pos;
+                return (diff.x + diff.y) as usize;
+            },
+            |&pos| pos == self.exit,
+        )
+        .map(|(_, len)| len);
+    }
+}
+
+pub struct Day18;
+
+impl AdventOfCode for Day18 {
+    type Parsed = Vec<Point>;
+    type Part1 = usize;
+    type Part2 = String;
+    type Params = Params;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| utils::parse_pair(line, ",").map_err(|err| err.at_line(i + 1)))
+            .map(|pair| pair.map(Point::from_pair))
+            .collect();
+    }
+
+    fn part1(incoming: &Self::Parsed, params: &Self::Params) -> AocResult<Self::Part1> {
+        let mem = MemorySpace::new(incoming, params.size);
+        return mem
+            .shortest_path(params.bytes)
+            .ok_or_else(|| AocError::solve("no path to the exit"));
+    }
+
+    fn part2(incoming: &Self::Parsed, params: &Self::Params) -> AocResult<Self::Part2> {
+        let mem = MemorySpace::new(incoming, params.size);
+        for i in params.bytes..mem.incoming.len() {
+            if mem.shortest_path(i).is_none() {
+                let curr = mem.incoming[i - 1];
+                return Ok(format!("{},{}", curr.x, curr.y));
+            }
+        }
+        return Err(AocError::solve("no byte blocks the path to the exit"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day18, Params};
+    use utils::{Point, PuzzleParams};
+
+    const PARAMS: Params = Params {
+        size: Point::new(7, 7),
+        bytes: 12,
+    };
+
+    const INPUT: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    utils::examples! {
+        Day18;
+        day18_part1: part1(INPUT, PARAMS) == 22,
+        day18_part2: part2(INPUT, PARAMS) == "6,1",
+    }
+
+    #[test]
+    fn day18_empty_size() {
+        for size in ["0,0", "7,0", "-1,7"] {
+            let overrides = [("size".to_string(), size.to_string())];
+            assert!(Params::from_overrides(&overrides).is_err(), "{size}");
+        }
+        let overrides = [("size".to_string(), "1,1".to_string())];
+        assert_eq!(
+            Params::from_overrides(&overrides).unwrap().size,
+            Point::xy(1)
+        );
+    }
+}
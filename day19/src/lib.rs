@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use utils::{AdventOfCode, AocResult};
+
+/// The available towel patterns and the designs to make from them.
+pub struct Onsen {
+    pub patterns: Vec<String>,
+    pub designs: Vec<String>,
+}
+
+impl Onsen {
+    pub fn new(input: &str) -> AocResult<Self> {
+        let [patterns, designs] = utils::sections(input).exactly()?;
+        Ok(Self {
+            patterns: patterns
+                .text
+                .split(',')
+                .map(|pat| pat.trim().to_string())
+                .collect(),
+            designs: designs
+                .text
+                .lines()
+                .map(|design| design.trim().to_string())
+                .collect(),
+        })
+    }
+
+    /// The number of ways `design` can be made from the available patterns.
+    pub fn arrangements(&self, design: &str) -> usize {
+        return self.num_designs(design, &mut HashMap::new());
+    }
+
+    fn num_designs<'b>(&self, design: &'b str, cache: &mut HashMap<&'b str, usize>) -> usize {
+        if design.is_empty() {
+            return 1;
+        }
+        if let Some(&result) = cache.get(design) {
+            return result;
+        }
+        let result = self
+            .patterns
+            .iter()
+            .filter_map(|pat| match design.starts_with(pat) {
+                true => Some(self.num_designs(&design[pat.len()..], cache)),
+                false => None,
+            })
+            .sum();
+
+        cache.insert(design, result);
+        return result;
+    }
+}
+
+pub struct Day19;
+
+impl AdventOfCode for Day19 {
+    type Parsed = Onsen;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return Onsen::new(input);
+    }
+
+    fn part1(onsen: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(onsen
+            .designs
+            .iter()
+            .filter(|design| onsen.arrangements(design) > 0)
+            .count());
+    }
+
+    fn part2(onsen: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(onsen
+            .designs
+            .iter()
+            .map(|design| onsen.arrangements(design))
+            .sum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day19;
+
+    const INPUT: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+    utils::examples! {
+        Day19;
+        day19_part1: part1(INPUT) == 6,
+        day19_part2: part2(INPUT) == 16,
+        day19_part1_crlf: part1(&format!("{}\r\n\r\n", INPUT.replace('\n', "\r\n"))) == 6,
+    }
+}
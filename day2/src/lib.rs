@@ -0,0 +1,67 @@
+use utils::{AdventOfCode, AocResult};
+
+fn parse_input(input: &str) -> Vec<Vec<isize>> {
+    return input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .filter_map(|chars| chars.parse().ok())
+                .collect()
+        })
+        .collect();
+}
+
+/// Whether the levels only increase or only decrease, by one to three at a time.
+pub fn is_safe(report: &[isize]) -> bool {
+    let mut diffs = report.windows(2).map(|pair| pair[0] - pair[1]);
+
+    let diff_sign = (report[0] - report[1]).signum();
+
+    return diffs.all(|diff| (1..4).contains(&diff.abs()) && diff.signum() == diff_sign);
+}
+
+pub struct Day2;
+
+impl AdventOfCode for Day2 {
+    type Parsed = Vec<Vec<isize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return Ok(parse_input(input));
+    }
+
+    fn part1(reports: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(reports.iter().filter(|report| is_safe(report)).count());
+    }
+
+    fn part2(reports: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(reports
+            .iter()
+            .filter(|report| {
+                is_safe(report)
+                    || (0..report.len())
+                        .any(|i| is_safe(&[&report[0..i], &report[i + 1..]].concat()))
+            })
+            .count());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day2;
+
+    const INPUT: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    utils::examples! {
+        Day2;
+        day2_part1: part1(INPUT) == 2,
+        day2_part2: part2(INPUT) == 4,
+    }
+}
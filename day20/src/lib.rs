@@ -0,0 +1,176 @@
+use dashmap::DashMap;
+use rayon::prelude::*;
+use utils::{AdventOfCode, AocError, AocResult, Direction, Grid, Point, PuzzleParams};
+
+pub struct Params {
+    /// The fewest picoseconds a cheat has to save to be counted.
+    pub min_saved: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { min_saved: 100 }
+    }
+}
+
+impl PuzzleParams for Params {
+    fn set(&mut self, key: &str, value: &str) -> AocResult<()> {
+        match key {
+            "min_saved" => self.min_saved = utils::parse_param(key, value)?,
+            _ => return Err(utils::unknown_param(key)),
+        }
+        return Ok(());
+    }
+}
+
+/// The racetrack map with its start and end.
+pub struct Racetrack {
+    walls: Grid<bool>,
+    start: Point,
+    end: Point,
+    /// The path without cheats, found while parsing so both parts share it.
+    best_path: Vec<Point>,
+}
+
+impl Racetrack {
+    pub fn new(input: &str) -> AocResult<Self> {
+        let chars = Grid::chars(input)?;
+
+        let start = chars
+            .position(&'S')
+            .ok_or_else(|| AocError::parse("failed to find start tile"))?;
+        let end = chars
+            .position(&'E')
+            .ok_or_else(|| AocError::parse("failed to find end tile"))?;
+        let walls = chars.map(|c| *c == '#');
+
+        let mut track = Self {
+            walls,
+            start,
+            end,
+            best_path: Vec::new(),
+        };
+        track.best_path = track.find_best_path()?;
+        Ok(track)
+    }
+
+    fn find_best_path(&self) -> AocResult<Vec<Point>> {
+        let (path, _) = utils::astar(
+            self.start,
+            |&p| {
+                Direction::all()
+                    .iter()
+                    .map(|dir| p + dir.offset())
+                    .filter(|&p| !self.is_wall(p))
+                    .map(|p| (p, 1))
+                    .collect::<Vec<_>>()
+            },
+            |p| p.rect_dist(self.end),
+            |&p| p == self.end,
+        )
+        .ok_or_else(|| AocError::solve("no path from start to end"))?;
+
+        return Ok(path);
+    }
+
+    pub fn best_path(&self) -> &[Point] {
+        return &self.best_path;
+    }
+
+    /// Whether `pos` is a wall, treating everything outside the map as one.
+    fn is_wall(&self, pos: Point) -> bool {
+        return self.walls.get(pos).copied().unwrap_or(true);
+    }
+
+    /// Counts the cheats of up to `allowed` picoseconds by how much time they
+    /// save, keeping those that save at least `min_saved`.
+    pub fn find_cheats(&self, allowed: isize, min_saved: usize) -> DashMap<usize, usize> {
+        let cheats = DashMap::new();
+        let path = self.best_path();
+
+        path.par_iter().enumerate().for_each(|(i, point)| {
+            for (j, end) in point
+                .within_manhattan(allowed)
+                .filter(|p| self.walls.contains(*p))
+                .filter_map(|p| path.iter().position(|path| *path == p).map(|j| (j, p)))
+                .filter(|(j, _)| *j > i)
+            {
+                let new_len =
+                    [&path[..i], &path[j..]].concat().len() + point.rect_dist(end) as usize;
+                let diff = path.len() - new_len;
+                if diff >= min_saved {
+                    cheats
+                        .entry(diff)
+                        .and_modify(|count| *count += 1)
+                        .or_insert(1);
+                }
+            }
+        });
+
+        return cheats;
+    }
+}
+
+pub struct Day20;
+
+impl AdventOfCode for Day20 {
+    type Parsed = Racetrack;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = Params;
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return Racetrack::new(input);
+    }
+
+    fn part1(track: &Self::Parsed, params: &Self::Params) -> AocResult<Self::Part1> {
+        let min = params.min_saved;
+        return Ok(track
+            .find_cheats(2, min)
+            .iter()
+            .filter_map(|entry| match *entry.key() >= min {
+                true => Some(*entry.value()),
+                false => None,
+            })
+            .sum());
+    }
+
+    fn part2(track: &Self::Parsed, params: &Self::Params) -> AocResult<Self::Part2> {
+        let min = params.min_saved;
+        return Ok(track
+            .find_cheats(20, min)
+            .iter()
+            .filter_map(|entry| match *entry.key() >= min {
+                true => Some(*entry.value()),
+                false => None,
+            })
+            .sum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day20, Params};
+
+    const INPUT: &str = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    utils::examples! {
+        Day20;
+        day20_part1: part1(INPUT, Params { min_saved: 10 }) == 10,
+        day20_part2: part2(INPUT, Params { min_saved: 70 }) == 41,
+    }
+}
@@ -0,0 +1,75 @@
+use regex::Regex;
+use utils::{AdventOfCode, AocResult};
+
+/// An instruction recovered from the corrupted memory.
+pub enum Instruction {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+pub struct Day3;
+
+impl AdventOfCode for Day3 {
+    type Parsed = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        let re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
+
+        return Ok(re
+            .captures_iter(input)
+            .filter_map(|c| match c.get(0).map(|m| m.as_str()) {
+                Some("do()") => Some(Instruction::Do),
+                Some("don't()") => Some(Instruction::Dont),
+                _ => {
+                    let left = c.get(1)?.as_str();
+                    let right = c.get(2)?.as_str();
+                    let (left, right) = utils::parse_tuple(left, right).ok()?;
+                    Some(Instruction::Mul(left, right))
+                }
+            })
+            .collect());
+    }
+
+    fn part1(instructions: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(instructions
+            .iter()
+            .map(|ins| match ins {
+                Instruction::Mul(left, right) => left * right,
+                _ => 0,
+            })
+            .sum());
+    }
+
+    fn part2(instructions: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        let mut total = 0;
+        let mut should_mul = true;
+
+        for ins in instructions {
+            match ins {
+                Instruction::Do => should_mul = true,
+                Instruction::Dont => should_mul = false,
+                Instruction::Mul(left, right) if should_mul => total += left * right,
+                Instruction::Mul(..) => {}
+            }
+        }
+
+        return Ok(total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day3;
+
+    const INPUT: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    utils::examples! {
+        Day3;
+        day3_part1: part1(INPUT) == 161,
+        day3_part2: part2(INPUT) == 48,
+    }
+}
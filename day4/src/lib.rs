@@ -0,0 +1,96 @@
+use utils::{AdventOfCode, AocResult, Direction8, Grid, Point};
+
+/// The letter grid to search for `XMAS`.
+pub struct WordSearch {
+    grid: Grid<char>,
+}
+
+impl WordSearch {
+    /// Reads one row of the grid per line.
+    pub fn new(input: &str) -> AocResult<Self> {
+        Ok(Self {
+            grid: Grid::chars(input)?,
+        })
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Whether `word` can be read starting at `pos` and moving by `step`.
+    fn reads(&self, word: &str, pos: Point, step: Point) -> bool {
+        return word
+            .chars()
+            .enumerate()
+            .all(|(i, c)| self.grid.get(pos + step * i as isize) == Some(&c));
+    }
+
+    /// Counts the `XMAS` words that start at the given cell, in any of the eight directions.
+    pub fn num_of_xmas(&self, pos: Point) -> usize {
+        return Direction8::all()
+            .iter()
+            .filter(|dir| self.reads("XMAS", pos, dir.offset()))
+            .count();
+    }
+
+    /// Whether the cell is the centre of two `MAS` words crossing in an X.
+    pub fn is_x_mas(&self, pos: Point) -> bool {
+        let mas = |from: Direction8| {
+            let start = pos + from.offset();
+            let step = from.opposite().offset();
+            self.reads("MAS", start, step) || self.reads("SAM", start, step)
+        };
+        return mas(Direction8::NorthWest) && mas(Direction8::NorthEast);
+    }
+}
+
+pub struct Day4;
+
+impl AdventOfCode for Day4 {
+    type Parsed = WordSearch;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return WordSearch::new(input);
+    }
+
+    fn part1(word_search: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(word_search
+            .grid()
+            .positions()
+            .map(|pos| word_search.num_of_xmas(pos))
+            .sum());
+    }
+
+    fn part2(word_search: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(word_search
+            .grid()
+            .positions()
+            .filter(|&pos| word_search.is_x_mas(pos))
+            .count());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day4;
+
+    const INPUT: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    utils::examples! {
+        Day4;
+        day4_part1: part1(INPUT) == 18,
+        day4_part2: part2(INPUT) == 9,
+    }
+}
@@ -0,0 +1,151 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use utils::{AdventOfCode, AocError, AocResult};
+
+pub type PageOrdering = HashMap<usize, Vec<usize>>;
+
+/// The pages of one safety manual update, in print order.
+pub struct Update {
+    pub pages: Vec<usize>,
+}
+
+impl Update {
+    pub fn new(pages: Vec<usize>) -> Self {
+        Self { pages }
+    }
+
+    pub fn middle_page(&self) -> usize {
+        return self.pages[self.pages.len() / 2];
+    }
+
+    /// Whether no page is printed after a page it has to come before.
+    pub fn is_in_order(&self, ordering: &PageOrdering) -> bool {
+        for (i, page) in self.pages.iter().enumerate() {
+            if let Some(page_order) = ordering.get(page) {
+                let remaining = &self.pages[i + 1..];
+                if page_order
+                    .iter()
+                    .any(|order| self.pages.contains(order) && !remaining.contains(order))
+                {
+                    return false;
+                }
+            }
+        }
+
+        return true;
+    }
+
+    /// Returns the update with its pages sorted by the ordering rules.
+    pub fn fix_order(&self, ordering: &PageOrdering) -> Update {
+        let mut pages = self.pages.clone();
+
+        pages.sort_by(|a, b| {
+            if ordering.get(a).is_some_and(|order| order.contains(b)) {
+                return Ordering::Less;
+            }
+            if ordering.get(b).is_some_and(|order| order.contains(a)) {
+                return Ordering::Greater;
+            }
+            return Ordering::Equal;
+        });
+
+        return Update { pages };
+    }
+}
+
+fn parse_input(input: &str) -> AocResult<(PageOrdering, Vec<Update>)> {
+    let [rules, updates] = utils::sections(input).exactly()?;
+
+    let mut ordering: PageOrdering = HashMap::new();
+
+    for (line_no, line) in rules.lines() {
+        let (key, value) = utils::parse_pair(line, "|").map_err(|err| err.at_line(line_no))?;
+
+        ordering
+            .entry(key)
+            .and_modify(|values| values.extend([value]))
+            .or_insert(vec![value]);
+    }
+
+    let updates = updates
+        .lines()
+        .map(|(line_no, line)| {
+            line.split(",")
+                .map(|num_str| num_str.parse::<usize>().ok())
+                .collect::<Option<Vec<_>>>()
+                .map(Update::new)
+                .ok_or_else(|| AocError::parse(format!("invalid update {line:?}")).at_line(line_no))
+        })
+        .collect::<AocResult<Vec<_>>>()?;
+
+    return Ok((ordering, updates));
+}
+
+pub struct Day5;
+
+impl AdventOfCode for Day5 {
+    type Parsed = (PageOrdering, Vec<Update>);
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return parse_input(input);
+    }
+
+    fn part1((ordering, updates): &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(updates
+            .iter()
+            .filter(|update| update.is_in_order(ordering))
+            .map(|update| update.middle_page())
+            .sum());
+    }
+
+    fn part2((ordering, updates): &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(updates
+            .iter()
+            .filter(|update| !update.is_in_order(ordering))
+            .map(|update| update.fix_order(ordering).middle_page())
+            .sum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day5;
+
+    const INPUT: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    utils::examples! {
+        Day5;
+        day5_part1: part1(INPUT) == 143,
+        day5_part2: part2(INPUT) == 123,
+    }
+}
@@ -0,0 +1,170 @@
+use utils::{AdventOfCode, AocError, AocResult, Direction, Grid, Point};
+
+#[derive(Debug, Clone, Copy)]
+enum Tile {
+    Blocked,
+    Empty,
+    Visited(Direction),
+}
+
+impl Tile {
+    fn new(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Self::Blocked),
+            '.' => Some(Self::Empty),
+            c => Direction::from_arrow(c).map(Self::Visited),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Guard {
+    pos: Point,
+    dir: Direction,
+}
+
+/// The lab map and the guard patrolling it.
+#[derive(Debug, Clone)]
+pub struct State {
+    guard: Guard,
+    tiles: Grid<Tile>,
+}
+
+impl State {
+    /// Parses the map, with the guard marked by an arrow showing where it faces.
+    pub fn new(input: &str) -> AocResult<Self> {
+        let tiles = Grid::parse(input, Tile::new)?;
+        let guard = tiles
+            .iter()
+            .find_map(|(pos, tile)| match tile {
+                Tile::Visited(dir) => Some(Guard { pos, dir: *dir }),
+                _ => None,
+            })
+            .ok_or_else(|| AocError::parse("failed to find guard"))?;
+
+        Ok(Self { tiles, guard })
+    }
+
+    /// Moves or turns the guard once, returning `false` once the guard has left the map.
+    pub fn take_turn(&mut self) -> bool {
+        if !self.tiles.contains(self.guard.pos) {
+            return false;
+        }
+        let next_pos = self.guard.pos + self.guard.dir.offset();
+
+        match self.tiles.get(next_pos) {
+            Some(Tile::Blocked) => self.guard.dir = self.guard.dir.turn_right(),
+            Some(Tile::Empty) => {
+                self.tiles[next_pos] = Tile::Visited(self.guard.dir);
+                self.guard.pos = next_pos;
+            }
+            _ => self.guard.pos = next_pos,
+        }
+
+        return true;
+    }
+
+    fn is_blocked(&self, pos: Point) -> bool {
+        self.tiles
+            .get(pos)
+            .is_some_and(|tile| matches!(tile, Tile::Blocked))
+    }
+
+    /// Whether placing an obstruction at `pos` traps the guard in a loop.
+    pub fn is_loop(&self, pos: Point) -> bool {
+        let mut new_state = self.clone();
+        new_state.tiles[pos] = Tile::Blocked;
+
+        loop {
+            if !new_state.tiles.contains(new_state.guard.pos) {
+                return false;
+            }
+            let next_pos = new_state.guard.pos + new_state.guard.dir.offset();
+
+            match new_state.tiles.get(next_pos) {
+                Some(next) => match next {
+                    Tile::Blocked => new_state.guard.dir = new_state.guard.dir.turn_right(),
+                    Tile::Empty => {
+                        new_state.tiles[next_pos] = Tile::Visited(new_state.guard.dir);
+                        new_state.guard.pos = next_pos;
+                    }
+                    Tile::Visited(dir) => {
+                        if new_state.guard.dir == *dir {
+                            return true;
+                        } else {
+                            new_state.guard.pos = next_pos;
+                        }
+                    }
+                },
+                _ => new_state.guard.pos = next_pos,
+            }
+        }
+    }
+
+    /// The number of distinct tiles the guard has walked on.
+    pub fn num_visited(&self) -> usize {
+        self.tiles
+            .values()
+            .filter(|tile| matches!(tile, Tile::Visited(_)))
+            .count()
+    }
+}
+
+pub struct Day6;
+
+impl AdventOfCode for Day6 {
+    type Parsed = State;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return State::new(input);
+    }
+
+    fn part1(state: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        let mut state = state.clone();
+        loop {
+            if !state.take_turn() {
+                return Ok(state.num_visited());
+            }
+        }
+    }
+
+    fn part2(state: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(state
+            .tiles
+            .positions()
+            .filter(|&pos| pos != state.guard.pos && !state.is_blocked(pos) && state.is_loop(pos))
+            .count());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day6;
+    use utils::AdventOfCode;
+
+    const INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    utils::examples! {
+        Day6;
+        day6_part1: part1(INPUT) == 41,
+        day6_part2: part2(INPUT) == 6,
+    }
+
+    #[test]
+    fn day6_invalid_tile() {
+        let err = Day6::parse("....\n..^?\n").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(4)));
+    }
+}
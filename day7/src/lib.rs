@@ -0,0 +1,118 @@
+use utils::{AdventOfCode, AocError, AocResult};
+
+/// A calibration equation whose operators are missing.
+pub struct Equation {
+    pub test: usize,
+    pub numbers: Vec<usize>,
+}
+
+/// An operator that can be placed between two numbers.
+pub enum Op {
+    Add,
+    Mul,
+    Concat,
+}
+
+impl Op {
+    pub fn exec(&self, left: usize, right: &usize) -> usize {
+        match self {
+            Op::Add => left + right,
+            Op::Mul => left * right,
+            Op::Concat => left * 10usize.pow(right.ilog10() + 1) + right,
+        }
+    }
+}
+
+impl Equation {
+    pub fn new(input: &str) -> Option<Self> {
+        let (test, numbers) = input.split_once(": ")?;
+
+        let test = test.parse().ok()?;
+
+        let numbers = numbers
+            .split(" ")
+            .filter_map(|num| num.parse().ok())
+            .collect();
+
+        Some(Self { test, numbers })
+    }
+
+    /// Whether some combination of `ops` makes the numbers evaluate to the test value.
+    pub fn is_valid(&self, ops: &[Op]) -> bool {
+        return ops.iter().any(|op| self.check(ops, 0, op, &self.numbers));
+    }
+
+    fn check(&self, ops: &[Op], prev: usize, op: &Op, rest: &[usize]) -> bool {
+        match rest {
+            [next] => op.exec(prev, next) == self.test,
+            [next, rest @ ..] => ops
+                .iter()
+                .any(|next_op| self.check(ops, op.exec(prev, next), next_op, rest)),
+            [] => false,
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Day7 {
+    /// Sums the test values of the equations that can be made true with `ops`.
+    pub fn sum_of_valid_equations(equations: &[Equation], ops: &[Op]) -> usize {
+        return equations
+            .iter()
+            .filter(|eq| eq.is_valid(ops))
+            .map(|eq| eq.test)
+            .sum();
+    }
+}
+
+impl AdventOfCode for Day7 {
+    type Parsed = Vec<Equation>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                Equation::new(line).ok_or_else(|| {
+                    AocError::parse(format!("invalid equation {line:?}")).at_line(i + 1)
+                })
+            })
+            .collect();
+    }
+
+    fn part1(equations: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(Self::sum_of_valid_equations(equations, &[Op::Add, Op::Mul]));
+    }
+
+    fn part2(equations: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(Self::sum_of_valid_equations(
+            equations,
+            &[Op::Add, Op::Mul, Op::Concat],
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day7;
+
+    const INPUT: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    utils::examples! {
+        Day7;
+        day7_part1: part1(INPUT) == 3749,
+        day7_part2: part2(INPUT) == 11387,
+    }
+}
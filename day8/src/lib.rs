@@ -0,0 +1,119 @@
+use std::collections::{HashMap, HashSet};
+
+use utils::{AdventOfCode, AocResult, Point, Rect};
+
+/// The antennas on the roof, grouped by frequency.
+pub struct Map {
+    bounds: Rect,
+    antennas: HashMap<char, Vec<Point>>,
+}
+
+impl Map {
+    pub fn new(input: &str) -> Self {
+        let bounds = Rect::from_size(
+            (
+                input.lines().next().map_or(0, str::len),
+                input.lines().count(),
+            )
+                .into(),
+        );
+
+        let mut antennas = HashMap::new();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate().filter(|(_, c)| *c != '.') {
+                antennas
+                    .entry(c)
+                    .and_modify(|vecs: &mut Vec<Point>| vecs.push((x, y).into()))
+                    .or_insert(vec![(x, y).into()]);
+            }
+        }
+
+        Self { bounds, antennas }
+    }
+
+    /// Finds every antinode inside the map, repeating along the line when `extend` is set.
+    pub fn antinodes(&self, extend: bool) -> HashSet<Point> {
+        let mut set = HashSet::new();
+
+        for antennas in self.antennas.values().filter(|a| a.len() > 1) {
+            for (left, right) in antennas
+                .iter()
+                .enumerate()
+                .flat_map(|(i, a)| antennas[i + 1..].iter().map(move |b| (a, b)))
+            {
+                let diff = *right - *left;
+                let mut potential = Vec::new();
+                if !extend {
+                    let left_anti = *left - diff;
+                    let right_anti = *right + diff;
+                    potential.extend_from_slice(&[left_anti, right_anti]);
+                } else {
+                    let mut curr = *left;
+                    while self.bounds.contains(curr) {
+                        potential.push(curr);
+                        curr -= diff;
+                    }
+                    curr = *right;
+                    while self.bounds.contains(curr) {
+                        potential.push(curr);
+                        curr += diff;
+                    }
+                }
+
+                for pot in potential {
+                    if self.bounds.contains(pot) {
+                        set.insert(pot);
+                    }
+                }
+            }
+        }
+
+        return set;
+    }
+}
+
+pub struct Day8;
+
+impl AdventOfCode for Day8 {
+    type Parsed = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return Ok(Map::new(input));
+    }
+
+    fn part1(map: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        return Ok(map.antinodes(false).len());
+    }
+
+    fn part2(map: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        return Ok(map.antinodes(true).len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day8;
+
+    const INPUT: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    utils::examples! {
+        Day8;
+        day8_part1: part1(INPUT) == 14,
+        day8_part2: part2(INPUT) == 34,
+    }
+}
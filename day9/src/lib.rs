@@ -0,0 +1,134 @@
+use std::iter::repeat_n;
+
+use utils::{AdventOfCode, AocError, AocResult};
+
+#[derive(Clone, Copy, Debug)]
+struct Block {
+    id: usize,
+    start: usize,
+    end: usize,
+}
+
+fn parse_digit(i: usize, c: char) -> AocResult<usize> {
+    return c
+        .to_digit(10)
+        .map(|num| num as usize)
+        .ok_or_else(|| AocError::parse(format!("invalid digit {c:?}")).at(1, i + 1));
+}
+
+pub struct Day9;
+
+impl AdventOfCode for Day9 {
+    type Parsed = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Params = ();
+
+    fn parse(input: &str) -> AocResult<Self::Parsed> {
+        return input
+            .trim()
+            .chars()
+            .enumerate()
+            .map(|(i, c)| parse_digit(i, c))
+            .collect();
+    }
+
+    fn part1(digits: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part1> {
+        let mut blocks = Vec::new();
+        let mut id = 0;
+
+        for (i, &num) in digits.iter().enumerate() {
+            match i % 2 == 0 {
+                true => {
+                    blocks.extend(repeat_n(Some(id), num));
+                    id += 1;
+                }
+                false => blocks.extend(repeat_n(None, num)),
+            }
+        }
+
+        let mut blocks_rev = blocks
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, id)| id.map(|id| (i, id)));
+
+        let mut last_rev_read = blocks.len();
+
+        return Ok(blocks
+            .iter()
+            .enumerate()
+            .map_while(|(i, c)| {
+                if i >= last_rev_read {
+                    return None;
+                }
+                match c {
+                    None => match blocks_rev.next() {
+                        Some((j, id)) => {
+                            last_rev_read = j;
+                            Some(id * i)
+                        }
+                        None => None,
+                    },
+                    Some(id) => Some(id * i),
+                }
+            })
+            .sum());
+    }
+
+    fn part2(digits: &Self::Parsed, _params: &Self::Params) -> AocResult<Self::Part2> {
+        let mut blocks = Vec::new();
+
+        let mut id = 0;
+        let mut cursor = 0;
+
+        for (i, &num) in digits.iter().enumerate() {
+            if i % 2 == 0 {
+                blocks.push(Block {
+                    id,
+                    start: cursor,
+                    end: cursor + num,
+                });
+                id += 1;
+            }
+            cursor += num;
+        }
+
+        for block in blocks.clone().iter().rev() {
+            for j in 0..blocks.len() - 1 {
+                let left = blocks[j];
+                let right = blocks[j + 1];
+                let len = block.end - block.start;
+                if len <= right.start - left.end && block.start > left.start {
+                    let block = blocks.iter_mut().find(|b| b.id == block.id).unwrap();
+                    block.start = left.end;
+                    block.end = left.end + len;
+                    break;
+                }
+            }
+            blocks.sort_by_key(|block| block.start);
+        }
+
+        return Ok(blocks
+            .iter()
+            .map(|block| {
+                (block.start..block.end)
+                    .map(|pos| pos * block.id)
+                    .sum::<usize>()
+            })
+            .sum());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day9;
+
+    const INPUT: &str = "2333133121414131402";
+
+    utils::examples! {
+        Day9;
+        day9_part1: part1(INPUT) == 1928,
+        day9_part2: part2(INPUT) == 2858,
+    }
+}
@@ -1,24 +1,48 @@
-use std::{env, fmt::Display, fs};
+use std::{any::Any, fmt::Display, process::ExitCode};
+
+use crate::{AocResult, Options, Override, Part, PuzzleParams, Solution};
 
 pub trait AdventOfCode {
-    type Output;
+    /// The input parsed once and shared by both parts.
+    type Parsed: Any;
+    type Part1: Display;
+    type Part2: Display;
+    type Params: PuzzleParams;
 
-    fn part1(input: &str) -> Self::Output;
-    fn part2(input: &str) -> Self::Output;
+    fn parse(input: &str) -> AocResult<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed, params: &Self::Params) -> AocResult<Self::Part1>;
+    fn part2(parsed: &Self::Parsed, params: &Self::Params) -> AocResult<Self::Part2>;
 
-    fn read_input_file(day: usize) -> std::io::Result<String> {
-        let mut current = env::current_dir()?;
-        current.push(format!("day{day}"));
-        current.push("input.txt");
-        fs::read_to_string(current.to_str().unwrap())
+    fn answer(parsed: &Self::Parsed, part: Part, overrides: &[Override]) -> AocResult<String> {
+        let params = Self::Params::from_overrides(overrides)?;
+        let output = match part {
+            Part::One => Self::part1(parsed, &params).map(|output| output.to_string()),
+            Part::Two => Self::part2(parsed, &params).map(|output| output.to_string()),
+        };
+        output.map_err(|err| err.in_part(part))
     }
 
-    fn run(day: usize)
+    /// Runs this day from the command line, failing if an answer errors,
+    /// differs from `answers.txt` or regresses.
+    fn run(day: usize) -> ExitCode
     where
-        Self::Output: Display,
+        Self: Sized,
     {
-        let input = Self::read_input_file(day).expect("failed to open input");
-        println!("Part 1: {}", Self::part1(&input));
-        println!("Part 2: {}", Self::part2(&input));
+        let options = match Options::from_env() {
+            Ok(options) if options.all || !options.days.is_empty() => {
+                eprintln!("`all` and --day are only supported by the aoc runner");
+                return ExitCode::FAILURE;
+            }
+            Ok(options) => options,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        match crate::run_solutions(&[Solution::new::<Self>(day)], &options).is_ok() {
+            true => ExitCode::SUCCESS,
+            false => ExitCode::FAILURE,
+        }
     }
 }
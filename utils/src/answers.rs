@@ -0,0 +1,116 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io::ErrorKind, path::PathBuf};
+
+use crate::{AocError, AocResult, Part};
+
+/// The outcome of comparing a computed answer with the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail(String),
+    New,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Pass => f.pad("PASS"),
+            Check::Fail(_) => f.pad("FAIL"),
+            Check::New => f.pad("NEW"),
+        }
+    }
+}
+
+/// Known-good answers for a day's real input, stored next to it in
+/// `answers.txt` as one `part: answer` line per part.
+#[derive(Debug, Clone)]
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<Part, String>,
+}
+
+impl Answers {
+    pub fn load(day: usize) -> AocResult<Self> {
+        let mut path = crate::day_dir(day).map_err(|err| err.in_day(day))?;
+        path.push("answers.txt");
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => {
+                return Err(AocError::input(format!(
+                    "failed to read {}: {err}",
+                    path.display()
+                )))
+            }
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            let (part, answer) = match line.split_once(": ") {
+                Some(("1", answer)) => (Part::One, answer),
+                Some(("2", answer)) => (Part::Two, answer),
+                _ => {
+                    return Err(AocError::parse(format!("invalid answer {line:?}"))
+                        .in_day(day)
+                        .at_line(i + 1))
+                }
+            };
+            answers.insert(part, answer.to_string());
+        }
+
+        return Ok(Self { path, answers });
+    }
+
+    pub fn save(&self) -> AocResult<()> {
+        let contents = self
+            .answers
+            .iter()
+            .map(|(part, answer)| format!("{part}: {answer}\n"))
+            .collect::<String>();
+
+        fs::write(&self.path, contents).map_err(|err| {
+            AocError::input(format!("failed to write {}: {err}", self.path.display()))
+        })
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.answers.get(&part).map(|answer| answer.as_str())
+    }
+
+    pub fn insert(&mut self, part: Part, answer: String) {
+        self.answers.insert(part, answer);
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Check {
+        match self.get(part) {
+            Some(expected) if expected == answer => Check::Pass,
+            Some(expected) => Check::Fail(expected.to_string()),
+            None => Check::New,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, path::PathBuf};
+
+    use super::{Answers, Check};
+    use crate::Part;
+
+    #[test]
+    fn check() {
+        let mut answers = Answers {
+            path: PathBuf::new(),
+            answers: BTreeMap::new(),
+        };
+        answers.insert(Part::One, "11".to_string());
+
+        assert_eq!(answers.check(Part::One, "11"), Check::Pass);
+        assert_eq!(
+            answers.check(Part::One, "12"),
+            Check::Fail("11".to_string())
+        );
+        assert_eq!(answers.check(Part::Two, "31"), Check::New);
+        assert_eq!(format!("{:<5}|", Check::New), "NEW  |");
+    }
+}
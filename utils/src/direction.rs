@@ -1,6 +1,6 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
-use crate::point::Point;
+use crate::{point::Point, AocError, AocResult};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
@@ -91,3 +91,208 @@ impl Direction {
         }
     }
 }
+
+impl Direction {
+    /// The direction an arrow glyph (`^`, `>`, `v` or `<`) points in.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+/// Accepts arrows (`^>v<`), compass letters (`NESW`) and `UDLR`, in either case.
+impl TryFrom<char> for Direction {
+    type Error = AocError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if let Some(dir) = Direction::from_arrow(value) {
+            return Ok(dir);
+        }
+        match value.to_ascii_uppercase() {
+            'N' | 'U' => Ok(Direction::North),
+            'E' | 'R' => Ok(Direction::East),
+            'S' | 'D' => Ok(Direction::South),
+            'W' | 'L' => Ok(Direction::West),
+            _ => Err(AocError::parse(format!("invalid direction {value:?}"))),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(AocError::parse(format!("invalid direction {s:?}"))),
+        }
+    }
+}
+
+/// Parses a string of moves such as `<^^>v`, ignoring whitespace and line
+/// breaks. Bad characters are reported with their line and column.
+pub fn parse_moves(input: &str) -> AocResult<Vec<Direction>> {
+    let mut moves = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c.is_whitespace() {
+                continue;
+            }
+            moves.push(Direction::try_from(c).map_err(|err| err.at(y + 1, x + 1))?);
+        }
+    }
+    return Ok(moves);
+}
+
+/// One of the eight compass directions, including the diagonals. Rotations
+/// are clockwise for `right` and anticlockwise for `left`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction8::North => write!(f, "North"),
+            Direction8::NorthEast => write!(f, "NorthEast"),
+            Direction8::East => write!(f, "East"),
+            Direction8::SouthEast => write!(f, "SouthEast"),
+            Direction8::South => write!(f, "South"),
+            Direction8::SouthWest => write!(f, "SouthWest"),
+            Direction8::West => write!(f, "West"),
+            Direction8::NorthWest => write!(f, "NorthWest"),
+        }
+    }
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from north.
+    pub fn all() -> [Direction8; 8] {
+        [
+            Direction8::North,
+            Direction8::NorthEast,
+            Direction8::East,
+            Direction8::SouthEast,
+            Direction8::South,
+            Direction8::SouthWest,
+            Direction8::West,
+            Direction8::NorthWest,
+        ]
+    }
+
+    pub fn offset(&self) -> Point {
+        match self {
+            Direction8::North => (0, -1).into(),
+            Direction8::NorthEast => (1, -1).into(),
+            Direction8::East => (1, 0).into(),
+            Direction8::SouthEast => (1, 1).into(),
+            Direction8::South => (0, 1).into(),
+            Direction8::SouthWest => (-1, 1).into(),
+            Direction8::West => (-1, 0).into(),
+            Direction8::NorthWest => (-1, -1).into(),
+        }
+    }
+
+    /// Rotates clockwise by `steps` eighths of a turn.
+    fn rotate(&self, steps: usize) -> Self {
+        let index = Self::all().iter().position(|dir| dir == self).unwrap();
+        return Self::all()[(index + steps) % 8];
+    }
+
+    pub fn turn_right_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn turn_left_45(&self) -> Self {
+        self.rotate(7)
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Direction8::NorthEast
+                | Direction8::SouthEast
+                | Direction8::SouthWest
+                | Direction8::NorthWest
+        )
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+/// Fails with the original direction if it is a diagonal.
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    fn try_from(value: Direction8) -> Result<Self, Self::Error> {
+        match value {
+            Direction8::North => Ok(Direction::North),
+            Direction8::East => Ok(Direction::East),
+            Direction8::South => Ok(Direction::South),
+            Direction8::West => Ok(Direction::West),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+/// A direction that moves by a fixed step, so grid queries can take either
+/// the four cardinal directions or all eight.
+pub trait Offset: Copy {
+    fn offset(&self) -> Point;
+}
+
+impl Offset for Direction {
+    fn offset(&self) -> Point {
+        Direction::offset(self)
+    }
+}
+
+impl Offset for Direction8 {
+    fn offset(&self) -> Point {
+        Direction8::offset(self)
+    }
+}
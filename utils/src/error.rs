@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use crate::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Input,
+    Parse,
+    Solve,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::Input => write!(f, "input error"),
+            ErrorKind::Parse => write!(f, "parse error"),
+            ErrorKind::Solve => write!(f, "solve error"),
+        }
+    }
+}
+
+/// An error raised while reading, parsing or solving a puzzle. Lines and
+/// columns are 1-based; the day and part are filled in by the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub day: Option<usize>,
+    pub part: Option<Part>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            day: None,
+            part: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn input(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Input, message)
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Parse, message)
+    }
+
+    pub fn solve(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Solve, message)
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    pub fn in_day(mut self, day: usize) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    pub fn in_part(mut self, part: Part) -> Self {
+        self.part.get_or_insert(part);
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "day {day}, part {part}: ")?,
+            (Some(day), None) => write!(f, "day {day}: ")?,
+            (None, Some(part)) => write!(f, "part {part}: ")?,
+            (None, None) => {}
+        }
+
+        write!(f, "{}", self.kind)?;
+
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, " at line {line}, column {column}")?,
+            (Some(line), None) => write!(f, " at line {line}")?,
+            _ => {}
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<std::io::Error> for AocError {
+    fn from(value: std::io::Error) -> Self {
+        Self::input(value.to_string())
+    }
+}
@@ -0,0 +1,34 @@
+/// Generates a `#[test]` for each puzzle example, parsing the input and
+/// comparing one part's answer against the expected value.
+///
+/// Each row names the test, the part to run, the example input and, when
+/// the example needs them, the puzzle parameters. Rows without parameters
+/// use `Params::default()`.
+///
+/// ```ignore
+/// utils::examples! {
+///     Day18;
+///     day18_part1: part1(INPUT, PARAMS) == 22,
+///     day18_part2: part2(INPUT, PARAMS) == "6,1",
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($day:ty; $($name:ident: $part:ident($input:expr $(, $params:expr)?) == $expected:expr),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                let parsed = <$day as $crate::AdventOfCode>::parse($input).unwrap();
+                let params = $crate::examples!(@params $day $(, $params)?);
+                let res = <$day as $crate::AdventOfCode>::$part(&parsed, &params).unwrap();
+                assert_eq!(res, $expected);
+            }
+        )+
+    };
+    (@params $day:ty) => {
+        <<$day as $crate::AdventOfCode>::Params as Default>::default()
+    };
+    (@params $day:ty, $params:expr) => {
+        $params
+    };
+}
@@ -0,0 +1,218 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{AocError, AocResult, Direction, Direction8, Offset, Point, Rect};
+
+/// A dense rectangular grid indexed by `Point`, with `(0, 0)` in the top left
+/// corner and `y` growing downwards.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    size: Point,
+}
+
+impl<T> Grid<T> {
+    pub fn new(size: Point, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let len = (size.x.max(0) * size.y.max(0)) as usize;
+        Self {
+            cells: vec![fill; len],
+            size,
+        }
+    }
+
+    pub fn from_fn(size: Point, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| Point::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Self { cells, size }
+    }
+
+    /// Parses one row per line, mapping each character to a cell. Fails on
+    /// characters the mapping rejects and on rows of different lengths.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> AocResult<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let mut len = 0;
+            for (x, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or_else(|| {
+                    AocError::parse(format!("invalid tile {c:?}")).at(y + 1, x + 1)
+                })?;
+                cells.push(cell);
+                len += 1;
+            }
+
+            if *width.get_or_insert(len) != len {
+                return Err(AocError::parse(format!(
+                    "expected {} tiles but found {len}",
+                    width.unwrap_or_default()
+                ))
+                .at_line(y + 1));
+            }
+            height += 1;
+        }
+
+        return Ok(Self {
+            cells,
+            size: Point::new(width.unwrap_or_default() as isize, height),
+        });
+    }
+
+    /// The width and height of the grid.
+    pub fn size(&self) -> Point {
+        self.size
+    }
+
+    pub fn width(&self) -> usize {
+        self.size.x as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.size.y as usize
+    }
+
+    /// The rectangle covering every cell.
+    pub fn bounds(&self) -> Rect {
+        Rect::from_size(self.size)
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.bounds().contains(pos)
+    }
+
+    fn index_of(&self, pos: Point) -> Option<usize> {
+        return self
+            .contains(pos)
+            .then(|| (pos.y * self.size.x + pos.x) as usize);
+    }
+
+    fn point_at(&self, index: usize) -> Point {
+        let width = self.width();
+        return Point::new((index % width) as isize, (index / width) as isize);
+    }
+
+    /// The cell at `pos`, or `None` if it lies outside the grid.
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        return self.index_of(pos).map(|i| &self.cells[i]);
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        return self.index_of(pos).map(|i| &mut self.cells[i]);
+    }
+
+    /// The position and cell one step from `pos` in `dir`, if it is inside the grid.
+    pub fn neighbor(&self, pos: Point, dir: impl Offset) -> Option<(Point, &T)> {
+        let next = pos + dir.offset();
+        return self.get(next).map(|cell| (next, cell));
+    }
+
+    /// The cells one step from `pos` in each of `dirs` that lie inside the grid.
+    pub fn neighbors_in<D: Offset>(
+        &self,
+        pos: Point,
+        dirs: impl IntoIterator<Item = D>,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        return dirs
+            .into_iter()
+            .filter_map(move |dir| self.neighbor(pos, dir));
+    }
+
+    /// The cells next to `pos` in the four cardinal directions that lie inside the grid.
+    pub fn neighbors(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        return self.neighbors_in(pos, Direction::all());
+    }
+
+    /// The cells around `pos`, diagonals included, that lie inside the grid.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = (Point, &T)> {
+        return self.neighbors_in(pos, Direction8::all());
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        return self.bounds().iter();
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        return self.positions().zip(self.cells.iter_mut());
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        return self.cells.iter();
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        return self
+            .cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.point_at(i));
+    }
+
+    /// The position of the first cell equal to `value`, such as a start marker.
+    pub fn position(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        return self.find(|cell| cell == value);
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            size: self.size,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses the input as-is, one character per cell.
+    pub fn chars(input: &str) -> AocResult<Self> {
+        return Self::parse(input, Some);
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside a grid of size {}", self.size))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        let size = self.size;
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos} is outside a grid of size {size}"))
+    }
+}
+
+/// Renders the grid back to text, one row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.cells.chunks(self.width().max(1)).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{AocError, AocResult};
+
+/// Environment variable naming a directory that holds the `day<N>/`
+/// directories, used instead of the workspace root.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `day<N>/input.txt` under `AOC_INPUT_DIR` or the workspace root.
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// Reads the input and normalizes it with `normalize_input`.
+    pub fn read(&self, day: usize) -> AocResult<String> {
+        let input = match self {
+            InputSource::Default => {
+                let mut path = day_dir(day)?;
+                path.push("input.txt");
+                read_file(&path)?
+            }
+            InputSource::Path(path) => read_file(path)?,
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| AocError::input(format!("failed to read stdin: {err}")))?;
+                input
+            }
+        };
+        Ok(normalize_input(&input))
+    }
+}
+
+/// Converts `\r\n` line endings to `\n`, strips trailing whitespace from every
+/// line and drops blank lines at the end, leaving one final newline. Leading
+/// blank lines are kept so error line numbers still match the file.
+pub fn normalize_input(input: &str) -> String {
+    let lines = input.lines().map(str::trim_end).collect::<Vec<_>>();
+    return match lines.iter().rposition(|line| !line.is_empty()) {
+        Some(last) => lines[..=last].join("\n") + "\n",
+        None => String::new(),
+    };
+}
+
+fn read_file(path: &Path) -> AocResult<String> {
+    fs::read_to_string(path)
+        .map_err(|err| AocError::input(format!("failed to read {}: {err}", path.display())))
+}
+
+/// Finds the nearest ancestor of the current directory whose `Cargo.toml`
+/// declares a workspace, falling back to the workspace `utils` was built in.
+fn workspace_root() -> AocResult<PathBuf> {
+    let current = env::current_dir()
+        .map_err(|err| AocError::input(format!("failed to read current directory: {err}")))?;
+
+    for dir in current.ancestors() {
+        let manifest = fs::read_to_string(dir.join("Cargo.toml"));
+        if manifest.is_ok_and(|manifest| manifest.contains("[workspace]")) {
+            return Ok(dir.to_path_buf());
+        }
+    }
+
+    let utils = Path::new(env!("CARGO_MANIFEST_DIR"));
+    return Ok(utils.parent().unwrap_or(utils).to_path_buf());
+}
+
+/// The directory holding a day's `input.txt` and `answers.txt`.
+pub fn day_dir(day: usize) -> AocResult<PathBuf> {
+    let mut dir = match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => workspace_root()?,
+    };
+    dir.push(format!("day{day}"));
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_input;
+
+    #[test]
+    fn normalize_crlf_and_whitespace() {
+        assert_eq!(normalize_input("a \r\nb\t\r\n"), "a\nb\n");
+    }
+
+    #[test]
+    fn normalize_blank_lines() {
+        assert_eq!(normalize_input("a\n\n  \nb\n\n \r\n\n"), "a\n\n\nb\n");
+        assert_eq!(normalize_input("\n\na\n"), "\n\na\n");
+        assert_eq!(normalize_input(" \r\n\n"), "");
+    }
+}
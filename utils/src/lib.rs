@@ -1,9 +1,34 @@
 mod advent_of_code;
+mod answers;
 mod direction;
+mod error;
+mod examples;
+mod grid;
+mod input;
+mod options;
+mod params;
+mod parse;
 mod point;
-mod tuples;
+mod rect;
+mod regions;
+mod runner;
+mod search;
+mod solution;
+mod timing;
 
 pub use crate::advent_of_code::*;
+pub use crate::answers::*;
 pub use crate::direction::*;
+pub use crate::error::*;
+pub use crate::grid::*;
+pub use crate::input::*;
+pub use crate::options::*;
+pub use crate::params::*;
+pub use crate::parse::*;
 pub use crate::point::*;
-pub use crate::tuples::*;
+pub use crate::rect::*;
+pub use crate::regions::*;
+pub use crate::runner::*;
+pub use crate::search::*;
+pub use crate::solution::*;
+pub use crate::timing::*;
@@ -0,0 +1,164 @@
+use std::{env, fmt::Display, path::PathBuf, str::FromStr};
+
+use crate::{InputSource, Override, Part};
+
+/// Command line options shared by the per-day binaries and the `aoc` runner.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub all: bool,
+    pub days: Vec<usize>,
+    pub part: Option<Part>,
+    pub input: InputSource,
+    /// `key=value` overrides for the puzzle parameters.
+    pub params: Vec<Override>,
+    /// Compare each answer with the day's `answers.txt`.
+    pub check: bool,
+    /// Write the computed answers to the day's `answers.txt`.
+    pub record: bool,
+    pub time: bool,
+    pub repeat: usize,
+    pub save: Option<PathBuf>,
+    pub compare: Option<PathBuf>,
+    /// Percentage slowdown of the median against `compare` that is reported
+    /// as a regression.
+    pub threshold: f64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            all: false,
+            days: Vec::new(),
+            part: None,
+            input: InputSource::Default,
+            params: Vec::new(),
+            check: false,
+            record: false,
+            time: false,
+            repeat: 1,
+            save: None,
+            compare: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+impl Options {
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(env::args().skip(1))
+    }
+
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "all" => options.all = true,
+                "--day" => {
+                    let day = Self::value(&arg, args.next())?;
+                    options.days.push(day);
+                }
+                "--part" => {
+                    options.part = match Self::value(&arg, args.next())? {
+                        1 => Some(Part::One),
+                        2 => Some(Part::Two),
+                        part => return Err(format!("invalid part: {part}")),
+                    }
+                }
+                "--input" => {
+                    let input = args.next().ok_or("missing value for --input")?;
+                    options.input = InputSource::from_arg(&input);
+                }
+                "--param" => {
+                    let param = args.next().ok_or("missing value for --param")?;
+                    let (key, value) = param
+                        .split_once('=')
+                        .ok_or_else(|| format!("expected key=value for --param: {param}"))?;
+                    options.params.push((key.to_string(), value.to_string()));
+                }
+                "--check" => options.check = true,
+                "--record" => options.record = true,
+                "--time" => options.time = true,
+                "--repeat" => match Self::value(&arg, args.next())? {
+                    0 => return Err("--repeat must be at least 1".to_string()),
+                    repeat => options.repeat = repeat,
+                },
+                "--save" => options.save = Some(Self::value(&arg, args.next())?),
+                "--compare" => options.compare = Some(Self::value(&arg, args.next())?),
+                "--threshold" => options.threshold = Self::value(&arg, args.next())?,
+                _ => return Err(format!("unexpected argument: {arg}")),
+            }
+        }
+
+        // answers.txt holds the answers for the real input with default parameters
+        if (options.check || options.record)
+            && (options.input != InputSource::Default || !options.params.is_empty())
+        {
+            return Err(
+                "--check and --record can't be combined with --input or --param".to_string(),
+            );
+        }
+
+        return Ok(options);
+    }
+
+    fn value<T>(flag: &str, value: Option<String>) -> Result<T, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = value.ok_or_else(|| format!("missing value for {flag}"))?;
+        value
+            .parse()
+            .map_err(|err| format!("invalid value for {flag}: {value} ({err})"))
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::all().to_vec(),
+        }
+    }
+
+    /// Whether any of the timing options were given.
+    pub fn timed(&self) -> bool {
+        self.time || self.repeat > 1 || self.save.is_some() || self.compare.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Options;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_flags() {
+        let options = parse(&["--day", "3", "--part", "2", "--check", "--repeat", "5"]).unwrap();
+        assert_eq!(options.days, [3]);
+        assert_eq!(options.parts().len(), 1);
+        assert!(options.check && options.timed());
+    }
+
+    #[test]
+    fn parse_rejections() {
+        for args in [
+            &["--day"][..],
+            &["--day", "x"],
+            &["--part", "3"],
+            &["--repeat", "0"],
+            &["--param", "size"],
+            &["--bogus"],
+            &["--check", "--input", "example.txt"],
+            &["--record", "--param", "size=7,7"],
+        ] {
+            assert!(parse(args).is_err(), "{args:?}");
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::str::FromStr;
+
+use crate::{AocError, AocResult, Point};
+
+/// A `key=value` pair given with `--param`.
+pub type Override = (String, String);
+
+/// Puzzle parameters that differ between the examples and the real input,
+/// such as grid sizes. `Default` gives the values for the real input.
+pub trait PuzzleParams: Default {
+    /// Applies a `key=value` override given with `--param`.
+    fn set(&mut self, key: &str, value: &str) -> AocResult<()>;
+
+    fn from_overrides(overrides: &[Override]) -> AocResult<Self> {
+        let mut params = Self::default();
+        for (key, value) in overrides {
+            params.set(key, value)?;
+        }
+        return Ok(params);
+    }
+}
+
+impl PuzzleParams for () {
+    fn set(&mut self, key: &str, _value: &str) -> AocResult<()> {
+        Err(unknown_param(key))
+    }
+}
+
+pub fn unknown_param(key: &str) -> AocError {
+    AocError::input(format!("unknown parameter {key:?}"))
+}
+
+pub fn parse_param<T: FromStr>(key: &str, value: &str) -> AocResult<T> {
+    value
+        .parse()
+        .map_err(|_| AocError::input(format!("invalid value for parameter {key:?}: {value:?}")))
+}
+
+/// Parses a grid size such as `11,7`, which needs at least one cell each way.
+pub fn parse_size_param(key: &str, value: &str) -> AocResult<Point> {
+    let size: Point = parse_param(key, value)?;
+    if size.x <= 0 || size.y <= 0 {
+        return Err(AocError::input(format!(
+            "parameter {key:?} must be positive in both directions: {value:?}"
+        )));
+    }
+    return Ok(size);
+}
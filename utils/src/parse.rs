@@ -0,0 +1,261 @@
+use std::str::FromStr;
+
+use crate::{AocError, AocResult};
+
+/// Parses both halves of a pair, ignoring surrounding whitespace.
+pub fn parse_tuple<A, B>(left: &str, right: &str) -> AocResult<(A, B)>
+where
+    A: FromStr,
+    B: FromStr,
+{
+    let parse_err = |s: &str| AocError::parse(format!("invalid value {s:?}"));
+    let left = left.trim().parse().map_err(|_| parse_err(left))?;
+    let right = right.trim().parse().map_err(|_| parse_err(right))?;
+    Ok((left, right))
+}
+
+/// Splits `line` around the first `separator`, trimming both halves, as in `a|b`.
+pub fn split_pair<'a>(line: &'a str, separator: &str) -> AocResult<(&'a str, &'a str)> {
+    return line
+        .split_once(separator)
+        .map(|(left, right)| (left.trim(), right.trim()))
+        .ok_or_else(|| AocError::parse(format!("expected {separator:?} in {line:?}")));
+}
+
+/// Splits and parses both halves of a line such as `47|53`.
+pub fn parse_pair<A, B>(line: &str, separator: &str) -> AocResult<(A, B)>
+where
+    A: FromStr,
+    B: FromStr,
+{
+    let (left, right) = split_pair(line, separator)?;
+    return parse_tuple(left, right);
+}
+
+/// Splits a `key: value` line into its trimmed key and value.
+pub fn key_value(line: &str) -> AocResult<(&str, &str)> {
+    return split_pair(line, ":");
+}
+
+/// Parses the value of a `key: value` line, checking the key is `key`.
+pub fn parse_value<T: FromStr>(line: &str, key: &str) -> AocResult<T> {
+    let (found, value) = key_value(line)?;
+    if found != key {
+        return Err(AocError::parse(format!(
+            "expected {key:?} but found {found:?}"
+        )));
+    }
+    return value
+        .parse()
+        .map_err(|_| AocError::parse(format!("invalid value for {key:?}: {value:?}")));
+}
+
+/// Every integer in `line`, in order, skipping any text around them. A `-`
+/// directly before the digits makes the number negative unless it follows
+/// another digit, so `3-4` is two positive numbers.
+pub fn ints<T: FromStr>(line: &str) -> AocResult<Vec<T>> {
+    let bytes = line.as_bytes();
+    let mut nums = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let negative = i > 0 && bytes[i - 1] == b'-' && (i < 2 || !bytes[i - 2].is_ascii_digit());
+        let start = if negative { i - 1 } else { i };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        let num = &line[start..i];
+        nums.push(
+            num.parse()
+                .map_err(|_| AocError::parse(format!("invalid number {num:?}")))?,
+        );
+    }
+
+    return Ok(nums);
+}
+
+/// Exactly `N` integers from line number `line_no` of the input, so a line with
+/// too few or too many reports where it is.
+pub fn fixed_ints<const N: usize, T: FromStr>(line: &str, line_no: usize) -> AocResult<[T; N]> {
+    let nums = ints(line).map_err(|err| err.at_line(line_no))?;
+    let found = nums.len();
+    return nums.try_into().map_err(|_| {
+        AocError::parse(format!("expected {N} numbers but found {found}")).at_line(line_no)
+    });
+}
+
+/// A block of consecutive non-blank lines from the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub text: &'a str,
+    /// The 1-based line number of the section's first line within the input.
+    pub line: usize,
+}
+
+impl<'a> Section<'a> {
+    /// The section's lines, each with its line number within the input.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        let first = self.line;
+        return self
+            .text
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| (first + i, line));
+    }
+
+    /// Moves an error located relative to this section to its line in the input.
+    pub fn locate(&self, mut err: AocError) -> AocError {
+        err.line = err.line.map(|line| line + self.line - 1);
+        return err;
+    }
+}
+
+/// Splits the input into sections separated by one or more blank lines,
+/// where a line holding only whitespace counts as blank.
+pub fn sections(input: &str) -> Sections<'_> {
+    Sections {
+        input,
+        offset: 0,
+        line: 1,
+    }
+}
+
+/// The iterator returned by `sections`.
+#[derive(Clone)]
+pub struct Sections<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+}
+
+impl<'a> Sections<'a> {
+    /// Exactly `N` sections, failing if the input has more or fewer.
+    pub fn exactly<const N: usize>(self) -> AocResult<[Section<'a>; N]> {
+        let sections = self.collect::<Vec<_>>();
+        let found = sections.len();
+        return sections.try_into().map_err(|_| {
+            AocError::parse(format!(
+                "expected {N} sections separated by blank lines but found {found}"
+            ))
+        });
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = Section<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start = None;
+        let mut end = self.offset;
+
+        for line in self.input[self.offset..].split_inclusive('\n') {
+            let blank = line.trim().is_empty();
+            match (blank, start) {
+                (true, Some(_)) => break,
+                (true, None) => {}
+                (false, None) => start = Some((end, self.line)),
+                (false, Some(_)) => {}
+            }
+            end += line.len();
+            self.line += 1;
+        }
+
+        self.offset = end;
+        let (start, line) = start?;
+        return Some(Section {
+            text: self.input[start..end].trim_end(),
+            line,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_signs() {
+        assert_eq!(ints::<i64>("p=0,4 v=3,-3").unwrap(), [0, 4, 3, -3]);
+        assert_eq!(ints::<i64>("3-4").unwrap(), [3, 4]);
+        assert_eq!(ints::<i64>("-5 x-6 --7").unwrap(), [-5, -6, -7]);
+        assert_eq!(ints::<i64>("Button A: X+94, Y+34").unwrap(), [94, 34]);
+        assert!(ints::<i64>("no numbers").unwrap().is_empty());
+    }
+
+    #[test]
+    fn ints_negative_unsigned() {
+        let err = ints::<u32>("1 -2").unwrap_err();
+        assert_eq!(err.message, "invalid number \"-2\"");
+    }
+
+    #[test]
+    fn fixed_ints_lines() {
+        assert_eq!(fixed_ints::<2, i32>("x=1, y=-2", 3).unwrap(), [1, -2]);
+
+        let err = fixed_ints::<2, i32>("1 2 3", 7).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(7), None));
+
+        let err = fixed_ints::<1, u8>("-1", 4).unwrap_err();
+        assert_eq!(err.line, Some(4));
+    }
+
+    #[test]
+    fn pairs() {
+        assert_eq!(parse_pair::<u8, u8>(" 47 | 53", "|").unwrap(), (47, 53));
+        assert_eq!(key_value("Register A: 729").unwrap(), ("Register A", "729"));
+        assert_eq!(
+            parse_value::<usize>("Register A: 729", "Register A").unwrap(),
+            729
+        );
+        assert!(parse_value::<usize>("Register B: 729", "Register A").is_err());
+        assert!(split_pair("47,53", "|").is_err());
+    }
+
+    #[test]
+    fn sections_blank_runs() {
+        let input = "\n\na\nb\n\n  \n\t\nc\n\n\n";
+        let found = sections(input).collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [
+                Section {
+                    text: "a\nb",
+                    line: 3
+                },
+                Section { text: "c", line: 8 },
+            ]
+        );
+    }
+
+    #[test]
+    fn sections_crlf() {
+        let [first, second] = sections("a\r\nb\r\n\r\nc\r\n\r\n").exactly().unwrap();
+        assert_eq!(first.lines().collect::<Vec<_>>(), [(1, "a"), (2, "b")]);
+        assert_eq!((second.text, second.line), ("c", 4));
+    }
+
+    #[test]
+    fn sections_wrong_count() {
+        assert!(sections("a\n\nb").exactly::<3>().is_err());
+        assert!(sections("a\n\nb\n\nc").exactly::<2>().is_err());
+        assert!(sections("").exactly::<1>().is_err());
+    }
+
+    #[test]
+    fn section_locate() {
+        let [_, second] = sections("a\nb\n\n\nc\nd").exactly().unwrap();
+        assert_eq!(second.line, 5);
+
+        let err = second.locate(AocError::parse("bad").at(2, 3));
+        assert_eq!((err.line, err.column), (Some(6), Some(3)));
+
+        let err = second.locate(AocError::parse("bad"));
+        assert_eq!((err.line, err.column), (None, None));
+    }
+}
@@ -1,60 +1,275 @@
 use std::{
-    fmt::Display,
-    isize,
-    ops::{Add, Div, Mul, Sub},
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+    str::FromStr,
 };
 
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
-pub struct Point {
-    pub x: isize,
-    pub y: isize,
+use crate::AocError;
+
+/// An integer type that can be used as a coordinate.
+pub trait Coord:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The distance between two values, which never overflows for unsigned types.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$t>::wrapping_add(self, rhs)
+                }
+
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    <$t>::wrapping_sub(self, rhs)
+                }
+
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$t>::wrapping_mul(self, rhs)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$t>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A 2D point. Most puzzles use the default `isize` coordinates, with `y`
+/// growing downwards.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Display for Point {
+impl<T: Display> Display for Point<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
     }
 }
 
-impl Point {
-    pub const ZERO: Point = Point::new(0, 0);
-
-    pub const fn new(x: isize, y: isize) -> Self {
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
-    pub fn from_pair(pair: (isize, isize)) -> Self {
+    pub fn from_pair(pair: (T, T)) -> Self {
         Self {
             x: pair.0,
             y: pair.1,
         }
     }
 
-    pub fn xy(v: isize) -> Self {
+    /// Converts both coordinates, or `None` if either doesn't fit in `U`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl<T: Coord> Point<T> {
+    pub const ZERO: Point<T> = Point::new(T::ZERO, T::ZERO);
+
+    pub fn xy(v: T) -> Self {
         Self { x: v, y: v }
     }
 
-    pub fn add_x(&self, x: isize) -> Point {
+    pub fn add_x(&self, x: T) -> Self {
         Self {
             x: self.x + x,
             y: self.y,
         }
     }
-    pub fn add_y(&self, y: isize) -> Point {
+    pub fn add_y(&self, y: T) -> Self {
         Self {
             x: self.x,
             y: self.y + y,
         }
     }
 
-    pub fn rect_dist(&self, other: Point) -> isize {
-        let diff = *self - other;
-        return diff.x.abs() + diff.y.abs();
+    pub fn rect_dist(&self, other: Self) -> T {
+        return self.x.distance(other.x) + self.y.distance(other.y);
+    }
+
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+        ))
+    }
+
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+        ))
+    }
+
+    pub fn checked_mul(&self, rhs: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(rhs)?,
+            self.y.checked_mul(rhs)?,
+        ))
+    }
+
+    pub fn wrapping_add(&self, rhs: Self) -> Self {
+        Self::new(self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y))
+    }
+
+    pub fn wrapping_sub(&self, rhs: Self) -> Self {
+        Self::new(self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y))
+    }
+
+    pub fn wrapping_mul(&self, rhs: T) -> Self {
+        Self::new(self.x.wrapping_mul(rhs), self.y.wrapping_mul(rhs))
+    }
+
+    pub fn chebyshev_dist(&self, other: Self) -> T {
+        return self.x.distance(other.x).max(self.y.distance(other.y));
+    }
+
+    /// Wraps each coordinate into `0..size`, as on a torus.
+    pub fn rem_euclid(&self, size: Self) -> Self {
+        Self::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
+
+    /// The four orthogonally adjacent points, skipping any that would overflow.
+    pub fn neighbors(&self) -> impl Iterator<Item = Self> {
+        let (x, y) = (self.x, self.y);
+        return [
+            y.checked_sub(T::ONE).map(|y| Self::new(x, y)),
+            x.checked_add(T::ONE).map(|x| Self::new(x, y)),
+            y.checked_add(T::ONE).map(|y| Self::new(x, y)),
+            x.checked_sub(T::ONE).map(|x| Self::new(x, y)),
+        ]
+        .into_iter()
+        .flatten();
+    }
+}
+
+impl<T: Coord + Neg<Output = T>> Point<T> {
+    /// Rotates a quarter turn clockwise around the origin, with `y` growing downwards.
+    pub fn rotate_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn anticlockwise around the origin, with `y` growing downwards.
+    pub fn rotate_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The points exactly `dist` steps away, moving only orthogonally.
+    pub fn at_manhattan(&self, dist: T) -> impl Iterator<Item = Self> {
+        let center = *self;
+        return span(-dist, dist).flat_map(move |dx| {
+            let dy = dist - dx.distance(T::ZERO);
+            let below = (dy != T::ZERO).then(|| center + Self::new(dx, dy));
+            [Some(center + Self::new(dx, -dy)), below]
+                .into_iter()
+                .flatten()
+        });
+    }
+
+    /// The points at most `dist` steps away, moving only orthogonally.
+    pub fn within_manhattan(&self, dist: T) -> impl Iterator<Item = Self> {
+        let center = *self;
+        return span(-dist, dist).flat_map(move |dx| {
+            let reach = dist - dx.distance(T::ZERO);
+            span(-reach, reach).map(move |dy| center + Self::new(dx, dy))
+        });
+    }
+
+    /// The points on the square ring `dist` steps away, diagonals included,
+    /// clockwise from the top left corner.
+    pub fn at_chebyshev(&self, dist: T) -> impl Iterator<Item = Self> {
+        let center = *self;
+        let edge = move || span(-dist, dist - T::ONE);
+        let top = edge().map(move |dx| center + Self::new(dx, -dist));
+        let right = edge().map(move |dy| center + Self::new(dist, dy));
+        let bottom = edge().map(move |dx| center + Self::new(-dx, dist));
+        let left = edge().map(move |dy| center + Self::new(-dist, -dy));
+        let only = (dist == T::ZERO).then_some(center);
+        return top.chain(right).chain(bottom).chain(left).chain(only);
+    }
+
+    /// The points in the square of radius `dist` around this one.
+    pub fn within_chebyshev(&self, dist: T) -> impl Iterator<Item = Self> {
+        let center = *self;
+        return span(-dist, dist)
+            .flat_map(move |dy| span(-dist, dist).map(move |dx| center + Self::new(dx, dy)));
+    }
+}
+
+/// Every value from `from` to `to` inclusive, since ranges of a generic
+/// coordinate can't be iterated.
+fn span<T: Coord>(from: T, to: T) -> impl Iterator<Item = T> {
+    return std::iter::successors((from <= to).then_some(from), move |&v| {
+        (v < to).then(|| v + T::ONE)
+    });
+}
+
+/// Parses `x,y`, the format puzzles use for coordinates.
+impl<T: FromStr> FromStr for Point<T> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_once(",")
+            .and_then(|(x, y)| crate::parse_tuple(x.trim(), y.trim()).ok())
+            .map(Point::from_pair)
+            .ok_or_else(|| AocError::parse(format!("invalid point {s:?}")))
     }
 }
 
-impl From<(isize, isize)> for Point {
-    fn from(value: (isize, isize)) -> Self {
+impl<T> From<(T, T)> for Point<T> {
+    fn from(value: (T, T)) -> Self {
         Self::new(value.0, value.1)
     }
 }
@@ -71,34 +286,288 @@ impl From<(usize, usize)> for Point {
     }
 }
 
-impl Add for Point {
-    type Output = Point;
+impl<T: Coord> Add for Point<T> {
+    type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
         Point::new(self.x + rhs.x, self.y + rhs.y)
     }
 }
 
-impl Sub for Point {
-    type Output = Point;
+impl<T: Coord> Sub for Point<T> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
         Point::new(self.x - rhs.x, self.y - rhs.y)
     }
 }
 
-impl Mul<isize> for Point {
-    type Output = Point;
+impl<T: Coord> Mul<T> for Point<T> {
+    type Output = Self;
 
-    fn mul(self, rhs: isize) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Self::new(self.x * rhs, self.y * rhs)
     }
 }
 
-impl Div<isize> for Point {
-    type Output = Point;
+impl<T: Coord> Div<T> for Point<T> {
+    type Output = Self;
 
-    fn div(self, rhs: isize) -> Self::Output {
+    fn div(self, rhs: T) -> Self::Output {
         Self::new(self.x / rhs, self.y / rhs)
     }
 }
+
+impl<T: Coord + Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coord> MulAssign<T> for Point<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+/// A 3D point with the same arithmetic as `Point`.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point3<T = isize> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts every coordinate, or `None` if any doesn't fit in `U`.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point3<U>> {
+        Some(Point3::new(
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+            self.z.try_into().ok()?,
+        ))
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub const ZERO: Point3<T> = Point3::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub fn xyz(v: T) -> Self {
+        Self::new(v, v, v)
+    }
+
+    pub fn rect_dist(&self, other: Self) -> T {
+        return self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z);
+    }
+
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(rhs.x)?,
+            self.y.checked_add(rhs.y)?,
+            self.z.checked_add(rhs.z)?,
+        ))
+    }
+
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(rhs.x)?,
+            self.y.checked_sub(rhs.y)?,
+            self.z.checked_sub(rhs.z)?,
+        ))
+    }
+
+    pub fn checked_mul(&self, rhs: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(rhs)?,
+            self.y.checked_mul(rhs)?,
+            self.z.checked_mul(rhs)?,
+        ))
+    }
+
+    pub fn wrapping_add(&self, rhs: Self) -> Self {
+        Self::new(
+            self.x.wrapping_add(rhs.x),
+            self.y.wrapping_add(rhs.y),
+            self.z.wrapping_add(rhs.z),
+        )
+    }
+
+    pub fn wrapping_sub(&self, rhs: Self) -> Self {
+        Self::new(
+            self.x.wrapping_sub(rhs.x),
+            self.y.wrapping_sub(rhs.y),
+            self.z.wrapping_sub(rhs.z),
+        )
+    }
+
+    pub fn wrapping_mul(&self, rhs: T) -> Self {
+        Self::new(
+            self.x.wrapping_mul(rhs),
+            self.y.wrapping_mul(rhs),
+            self.z.wrapping_mul(rhs),
+        )
+    }
+
+    pub fn chebyshev_dist(&self, other: Self) -> T {
+        return self
+            .x
+            .distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z));
+    }
+
+    /// Wraps each coordinate into `0..size`, as on a torus.
+    pub fn rem_euclid(&self, size: Self) -> Self {
+        Self::new(
+            self.x.rem_euclid(size.x),
+            self.y.rem_euclid(size.y),
+            self.z.rem_euclid(size.z),
+        )
+    }
+
+    /// The six points sharing a face with this one, skipping any that would overflow.
+    pub fn neighbors(&self) -> impl Iterator<Item = Self> {
+        let (x, y, z) = (self.x, self.y, self.z);
+        return [
+            x.checked_sub(T::ONE).map(|x| Self::new(x, y, z)),
+            x.checked_add(T::ONE).map(|x| Self::new(x, y, z)),
+            y.checked_sub(T::ONE).map(|y| Self::new(x, y, z)),
+            y.checked_add(T::ONE).map(|y| Self::new(x, y, z)),
+            z.checked_sub(T::ONE).map(|z| Self::new(x, y, z)),
+            z.checked_add(T::ONE).map(|z| Self::new(x, y, z)),
+        ]
+        .into_iter()
+        .flatten();
+    }
+}
+
+/// Parses `x,y,z`.
+impl<T: FromStr> FromStr for Point3<T> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coords = s.split(",").map(|coord| coord.trim().parse().ok());
+        match (coords.next(), coords.next(), coords.next(), coords.next()) {
+            (Some(Some(x)), Some(Some(y)), Some(Some(z)), None) => Ok(Point3::new(x, y, z)),
+            _ => Err(AocError::parse(format!("invalid point {s:?}"))),
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from(value: (T, T, T)) -> Self {
+        Self::new(value.0, value.1, value.2)
+    }
+}
+
+impl<T: Coord> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coord> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coord> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Coord> Div<T> for Point3<T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T: Coord + Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coord> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coord> MulAssign<T> for Point3<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::Point;
+
+    #[test]
+    fn neighbourhoods() {
+        let center = Point::new(2i32, -1);
+        for dist in 0..4 {
+            let ring = center.at_chebyshev(dist).collect::<Vec<_>>();
+            assert_eq!(ring.len(), (8 * dist).max(1) as usize);
+            assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+            assert!(ring.iter().all(|p| p.chebyshev_dist(center) == dist));
+
+            let square = center.within_chebyshev(dist).count();
+            assert_eq!(square, ((2 * dist + 1) * (2 * dist + 1)) as usize);
+
+            let diamond = center.at_manhattan(dist).collect::<HashSet<_>>();
+            assert_eq!(diamond.len(), (4 * dist).max(1) as usize);
+            assert!(diamond.iter().all(|p| p.rect_dist(center) == dist));
+
+            let within = center.within_manhattan(dist).collect::<HashSet<_>>();
+            assert_eq!(within.len(), (2 * dist * (dist + 1) + 1) as usize);
+            assert!(within.iter().all(|p| p.rect_dist(center) <= dist));
+        }
+        assert_eq!(center.at_chebyshev(1).next(), Some(Point::new(1, -2)));
+    }
+}
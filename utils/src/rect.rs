@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+use crate::Point;
+
+/// An axis-aligned rectangle of points covering `min.x..max.x` and
+/// `min.y..max.y`, so `max` itself is outside it.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Display for Rect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.min, self.max)
+    }
+}
+
+impl Rect {
+    pub const fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// The rectangle from the origin covering `size.x` columns and `size.y` rows.
+    pub const fn from_size(size: Point) -> Self {
+        Self::new(Point::new(0, 0), size)
+    }
+
+    pub fn width(&self) -> isize {
+        (self.max.x - self.min.x).max(0)
+    }
+
+    pub fn height(&self) -> isize {
+        (self.max.y - self.min.y).max(0)
+    }
+
+    pub fn size(&self) -> Point {
+        Point::new(self.width(), self.height())
+    }
+
+    pub fn area(&self) -> usize {
+        (self.width() * self.height()) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        (self.min.x..self.max.x).contains(&pos.x) && (self.min.y..self.max.y).contains(&pos.y)
+    }
+
+    /// Every point in the rectangle, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = Point> {
+        let Rect { min, max } = *self;
+        return (min.y..max.y).flat_map(move |y| (min.x..max.x).map(move |x| Point::new(x, y)));
+    }
+
+    /// The overlap of two rectangles, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect::new(
+            Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y)),
+            Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y)),
+        );
+        return (!rect.is_empty()).then_some(rect);
+    }
+
+    /// Splits into top left, top right, bottom left and bottom right quarters.
+    /// When a side has odd length its middle row or column belongs to no quarter.
+    pub fn split_quadrants(&self) -> [Rect; 4] {
+        let half = self.size() / 2;
+        let left = self.min.x..self.min.x + half.x;
+        let right = self.max.x - half.x..self.max.x;
+        let top = self.min.y..self.min.y + half.y;
+        let bottom = self.max.y - half.y..self.max.y;
+
+        let rect = |xs: &std::ops::Range<isize>, ys: &std::ops::Range<isize>| {
+            Rect::new(Point::new(xs.start, ys.start), Point::new(xs.end, ys.end))
+        };
+        return [
+            rect(&left, &top),
+            rect(&right, &top),
+            rect(&left, &bottom),
+            rect(&right, &bottom),
+        ];
+    }
+
+    /// The point in the rectangle closest to `pos`. The rectangle must not be empty.
+    pub fn clamp(&self, pos: Point) -> Point {
+        Point::new(
+            pos.x.clamp(self.min.x, self.max.x - 1),
+            pos.y.clamp(self.min.y, self.max.y - 1),
+        )
+    }
+}
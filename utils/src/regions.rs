@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use crate::{Direction, Grid, Point, Rect};
+
+/// A connected group of equivalent grid cells.
+#[derive(Clone, Debug)]
+pub struct Region {
+    /// The index of the region in the list `connected_components` returns.
+    pub label: usize,
+    pub cells: HashSet<Point>,
+    /// The smallest rectangle holding every cell.
+    pub bounds: Rect,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.cells.contains(&pos)
+    }
+
+    /// The number of cell edges that border something outside the region.
+    pub fn perimeter(&self) -> usize {
+        return self
+            .cells
+            .iter()
+            .flat_map(|cell| Direction::all().map(|dir| *cell + dir.offset()))
+            .filter(|pos| !self.contains(*pos))
+            .count();
+    }
+
+    /// The number of straight sides around the region, holes included.
+    ///
+    /// Every side ends in exactly one corner, so this counts corners: outer
+    /// corners where both neighbours round a turn are outside the region, and
+    /// inner corners where both are inside but the diagonal between them isn't.
+    pub fn sides(&self) -> usize {
+        return self
+            .cells
+            .iter()
+            .flat_map(|cell| {
+                Direction::all().map(|dir| {
+                    let side = *cell + dir.offset();
+                    let next = *cell + dir.turn_right().offset();
+                    let diagonal = side + dir.turn_right().offset();
+                    match (self.contains(side), self.contains(next)) {
+                        (false, false) => true,
+                        (true, true) => !self.contains(diagonal),
+                        _ => false,
+                    }
+                })
+            })
+            .filter(|corner| *corner)
+            .count();
+    }
+}
+
+/// Splits the grid into regions of cells joined through their cardinal
+/// neighbours, where `same` decides whether two neighbouring cells belong
+/// together. Regions are labeled in the order their first cell appears, row by
+/// row, and every cell lands in exactly one region.
+pub fn connected_components<T>(
+    grid: &Grid<T>,
+    mut same: impl FnMut(&T, &T) -> bool,
+) -> Vec<Region> {
+    let mut labels: Grid<Option<usize>> = Grid::new(grid.size(), None);
+    let mut regions = Vec::new();
+
+    for start in grid.positions() {
+        if labels[start].is_some() {
+            continue;
+        }
+
+        let label = regions.len();
+        let mut cells = HashSet::from([start]);
+        let mut bounds = Rect::new(start, start + Point::xy(1));
+        let mut stack = vec![start];
+        labels[start] = Some(label);
+
+        while let Some(pos) = stack.pop() {
+            for (next, cell) in grid.neighbors(pos) {
+                if labels[next].is_none() && same(&grid[pos], cell) {
+                    labels[next] = Some(label);
+                    cells.insert(next);
+                    bounds.min = Point::new(bounds.min.x.min(next.x), bounds.min.y.min(next.y));
+                    bounds.max =
+                        Point::new(bounds.max.x.max(next.x + 1), bounds.max.y.max(next.y + 1));
+                    stack.push(next);
+                }
+            }
+        }
+
+        regions.push(Region {
+            label,
+            cells,
+            bounds,
+        });
+    }
+
+    return regions;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Grid, Point, Rect};
+
+    #[test]
+    fn large_single_region() {
+        // deep enough to overflow the stack with a recursive flood fill
+        let grid = Grid::new(Point::new(1000, 1000), 'A');
+        let regions = super::connected_components(&grid, |a, b| a == b);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].area(), 1_000_000);
+        assert_eq!(regions[0].bounds, grid.bounds());
+    }
+
+    #[test]
+    fn labels_and_bounds() {
+        let grid = Grid::chars("AAB\nCAB\nCCB").unwrap();
+        let regions = super::connected_components(&grid, |a, b| a == b);
+
+        let summary = regions
+            .iter()
+            .map(|region| (region.label, region.area(), region.bounds))
+            .collect::<Vec<_>>();
+        let rect = |x1, y1, x2, y2| Rect::new(Point::new(x1, y1), Point::new(x2, y2));
+        assert_eq!(
+            summary,
+            [
+                (0, 3, rect(0, 0, 2, 2)),
+                (1, 3, rect(2, 0, 3, 3)),
+                (2, 3, rect(0, 1, 2, 3)),
+            ]
+        );
+        assert!(regions[0].contains(Point::new(1, 1)));
+        assert_eq!((regions[0].perimeter(), regions[0].sides()), (8, 6));
+        assert_eq!((regions[1].perimeter(), regions[1].sides()), (8, 4));
+    }
+}
@@ -0,0 +1,276 @@
+use std::time::Duration;
+
+use crate::{Answers, AocError, Benchmarks, Check, InputSource, Options, Phase, Solution, Timing};
+
+/// Counts of the problems found by `run_solutions`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Summary {
+    pub errors: usize,
+    pub failures: usize,
+    pub regressions: usize,
+}
+
+impl Summary {
+    pub fn is_ok(&self) -> bool {
+        self.errors == 0 && self.failures == 0 && self.regressions == 0
+    }
+}
+
+struct Column {
+    title: &'static str,
+    width: usize,
+    right: bool,
+}
+
+impl Column {
+    const fn new(title: &'static str, width: usize, right: bool) -> Self {
+        Self {
+            title,
+            width,
+            right,
+        }
+    }
+}
+
+struct Table<'a> {
+    options: &'a Options,
+    columns: Vec<Column>,
+    baseline: Option<Benchmarks>,
+    benchmarks: Benchmarks,
+}
+
+impl<'a> Table<'a> {
+    fn new(options: &'a Options, baseline: Option<Benchmarks>) -> Self {
+        let mut columns = vec![Column::new("Day", 3, true), Column::new("Part", 5, true)];
+        if options.check {
+            columns.push(Column::new("Status", 6, false));
+        }
+        columns.push(Column::new("Answer", 20, false));
+        if options.timed() {
+            columns.extend([
+                Column::new("Min", 10, true),
+                Column::new("Median", 10, true),
+                Column::new("Max", 10, true),
+                Column::new("Change", 0, false),
+            ]);
+        }
+
+        Self {
+            options,
+            columns,
+            baseline,
+            benchmarks: Benchmarks::default(),
+        }
+    }
+
+    fn print(&self, cells: &[String]) {
+        let row = self
+            .columns
+            .iter()
+            .zip(cells)
+            .map(|(column, cell)| match column.right {
+                true => format!("{cell:>width$}", width = column.width),
+                false => format!("{cell:<width$}", width = column.width),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", row.trim_end());
+    }
+
+    fn header(&self) {
+        let titles = self.columns.iter().map(|c| c.title.to_string());
+        self.print(&titles.collect::<Vec<_>>());
+        let rules = self
+            .columns
+            .iter()
+            .map(|c| "-".repeat(c.width.max(c.title.len())));
+        self.print(&rules.collect::<Vec<_>>());
+    }
+
+    fn error(&self, day: usize, phase: Option<Phase>, err: &AocError) {
+        let phase = phase.map_or("-".to_string(), |phase| phase.to_string());
+        println!("{day:>3}  {phase:>5}  error: {err}");
+    }
+
+    /// Prints one row, returning whether its timing regressed against the
+    /// baseline.
+    fn row(
+        &mut self,
+        day: usize,
+        phase: Phase,
+        answer: &str,
+        check: Option<&Check>,
+        timing: &Timing,
+    ) -> bool {
+        let mut cells = vec![day.to_string(), phase.to_string()];
+        if self.options.check {
+            cells.push(check.map_or(String::new(), |check| check.to_string()));
+        }
+        match check {
+            Some(Check::Fail(expected)) => cells.push(format!("{answer} (expected {expected})")),
+            _ => cells.push(answer.to_string()),
+        }
+
+        let mut regressed = false;
+        if self.options.timed() {
+            self.benchmarks.insert(day, phase, timing.median());
+
+            let change = match self.baseline.as_ref().and_then(|b| b.get(day, phase)) {
+                Some(previous) => {
+                    let (change, is_regression) = self.change(previous, timing.median());
+                    regressed = is_regression;
+                    change
+                }
+                None => String::new(),
+            };
+
+            cells.extend([
+                format!("{:.2?}", timing.min()),
+                format!("{:.2?}", timing.median()),
+                format!("{:.2?}", timing.max()),
+                change,
+            ]);
+        }
+
+        self.print(&cells);
+        return regressed;
+    }
+
+    fn change(&self, previous: Duration, current: Duration) -> (String, bool) {
+        let ratio = current.as_secs_f64() / previous.as_secs_f64().max(f64::EPSILON);
+        let percent = (ratio - 1.0) * 100.0;
+        let regressed = percent > self.options.threshold;
+
+        match regressed {
+            true => (format!("{percent:+.1}% REGRESSION"), true),
+            false => (format!("{percent:+.1}%"), false),
+        }
+    }
+}
+
+/// Runs every solution for the parts selected in `options`, printing one
+/// table row per answer. Depending on `options` it also times each phase,
+/// checks the answers against `answers.txt` or records them there.
+pub fn run_solutions(solutions: &[Solution], options: &Options) -> Summary {
+    let mut summary = Summary::default();
+
+    let baseline = match &options.compare {
+        Some(path) => match Benchmarks::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(err) => {
+                eprintln!("{err}");
+                summary.errors += 1;
+                None
+            }
+        },
+        None => None,
+    };
+
+    let mut table = Table::new(options, baseline);
+    table.header();
+
+    for solution in solutions {
+        let day = solution.day;
+        // stdin can only be read once, so it is never re-read for timing
+        let repeat = match options.input {
+            InputSource::Stdin => 1,
+            _ => options.repeat,
+        };
+        let (input, timing) = Timing::measure(repeat, || options.input.read(day));
+
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                table.error(day, None, &err.in_day(day));
+                summary.errors += 1;
+                continue;
+            }
+        };
+
+        let mut answers = match options.check || options.record {
+            true => match Answers::load(day) {
+                Ok(answers) => Some(answers),
+                Err(err) => {
+                    table.error(day, None, &err);
+                    summary.errors += 1;
+                    continue;
+                }
+            },
+            false => None,
+        };
+
+        if options.timed() && table.row(day, Phase::Input, "", None, &timing) {
+            summary.regressions += 1;
+        }
+
+        let (parsed, timing) = Timing::measure(options.repeat, || solution.parse(&input));
+
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                table.error(day, Some(Phase::Parse), &err);
+                summary.errors += 1;
+                continue;
+            }
+        };
+
+        if options.timed() && table.row(day, Phase::Parse, "", None, &timing) {
+            summary.regressions += 1;
+        }
+
+        for part in options.parts() {
+            let phase = Phase::Part(part);
+            let (answer, timing) = Timing::measure(options.repeat, || {
+                solution.solve(parsed.as_ref(), part, &options.params)
+            });
+
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(err) => {
+                    table.error(day, Some(phase), &err);
+                    summary.errors += 1;
+                    continue;
+                }
+            };
+
+            let check = match (options.check, &answers) {
+                (true, Some(answers)) => Some(answers.check(part, &answer)),
+                _ => None,
+            };
+            if matches!(check, Some(Check::Fail(_))) {
+                summary.failures += 1;
+            }
+
+            if table.row(day, phase, &answer, check.as_ref(), &timing) {
+                summary.regressions += 1;
+            }
+
+            if let (true, Some(answers)) = (options.record, &mut answers) {
+                answers.insert(part, answer);
+            }
+        }
+
+        if let (true, Some(answers)) = (options.record, &answers) {
+            if let Err(err) = answers.save() {
+                table.error(day, None, &err.in_day(day));
+                summary.errors += 1;
+            }
+        }
+    }
+
+    if let Some(path) = &options.save {
+        if let Err(err) = table.benchmarks.save(path) {
+            eprintln!("{err}");
+            summary.errors += 1;
+        }
+    }
+
+    if summary.failures > 0 {
+        println!("{} answer(s) differ from answers.txt", summary.failures);
+    }
+    if summary.regressions > 0 {
+        println!("{} timing regression(s)", summary.regressions);
+    }
+
+    return summary;
+}
@@ -0,0 +1,420 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A path cost. `Default` is the cost of the empty path.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// A state waiting in the priority queue, ordered so the cheapest pops first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Follows single predecessors back from `end` to the start.
+fn rebuild_path<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, end: S) -> Vec<S> {
+    let mut path = vec![end];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    return path;
+}
+
+/// The number of steps from `start` to every state it can reach.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut dists = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let dist = dists[&state];
+        for next in successors(&state) {
+            if !dists.contains_key(&next) {
+                dists.insert(next.clone(), dist + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    return dists;
+}
+
+/// The shortest path from `start` to a goal when every step costs the same,
+/// including both ends.
+pub fn bfs_path<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(rebuild_path(&parents, state));
+        }
+        for next in successors(&state) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    return None;
+}
+
+/// The cheapest path from `start` to a goal and its cost.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    return astar(start, successors, |_| C::default(), is_goal);
+}
+
+/// The cheapest path from `start` to a goal and its cost, guided by a
+/// `heuristic` that must never overestimate the remaining cost.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut queue = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+
+    while let Some(Entry { cost, state, .. }) = queue.pop() {
+        if costs.get(&state).is_some_and(|best| *best < cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some((rebuild_path(&parents, state), cost));
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|best| next_cost < *best) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), state.clone());
+                queue.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    return None;
+}
+
+/// The cheapest cost from `start` to every reachable state, keeping every
+/// predecessor that lies on a cheapest path so ties aren't lost.
+///
+/// Every step must cost more than nothing. A zero-cost cycle would give a
+/// state infinitely many cheapest paths, and the predecessors could no longer
+/// form a DAG.
+pub fn dijkstra_all<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut settled = HashSet::new();
+    let mut queue = BinaryHeap::from([Entry {
+        priority: C::default(),
+        cost: C::default(),
+        state: start.clone(),
+    }]);
+
+    while let Some(Entry { cost, state, .. }) = queue.pop() {
+        if !settled.insert(state.clone()) {
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            debug_assert!(step > C::default(), "step costs must be positive");
+            let next_cost = cost + step;
+            match costs.get(&next).map(|best| next_cost.cmp(best)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                }
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Entry {
+                        priority: next_cost,
+                        cost: next_cost,
+                        state: next,
+                    });
+                }
+            }
+        }
+    }
+
+    return ShortestPaths {
+        start,
+        costs,
+        predecessors,
+    };
+}
+
+/// Every cheapest path out of a start state, stored as the DAG of
+/// predecessors that `dijkstra_all` found.
+pub struct ShortestPaths<S, C> {
+    start: S,
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> ShortestPaths<S, C> {
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// The cheapest cost to reach `state`, or `None` if it is unreachable.
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// Every reachable state with its cheapest cost.
+    pub fn costs(&self) -> &HashMap<S, C> {
+        &self.costs
+    }
+
+    /// The states one step before `state` on some cheapest path to it.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// The cheapest of `states` and its cost, picking the first on ties.
+    pub fn cheapest<'a>(&self, states: impl IntoIterator<Item = &'a S>) -> Option<(&'a S, C)>
+    where
+        S: 'a,
+    {
+        return states
+            .into_iter()
+            .filter_map(|state| self.cost(state).map(|cost| (state, cost)))
+            .min_by_key(|(_, cost)| *cost);
+    }
+
+    /// One cheapest path from the start to `end`, including both ends.
+    pub fn path_to(&self, end: &S) -> Option<Vec<S>> {
+        self.costs.get(end)?;
+        let mut path = vec![end.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        return Some(path);
+    }
+
+    /// Every cheapest path from the start to `end`. The number of paths can
+    /// grow exponentially, so prefer `count_paths` or `states_on_paths`.
+    pub fn paths_to(&self, end: &S) -> Vec<Vec<S>> {
+        if !self.costs.contains_key(end) {
+            return Vec::new();
+        }
+        let preds = self.predecessors(end);
+        if preds.is_empty() {
+            return vec![vec![end.clone()]];
+        }
+        return preds
+            .iter()
+            .flat_map(|prev| self.paths_to(prev))
+            .map(|mut path| {
+                path.push(end.clone());
+                path
+            })
+            .collect();
+    }
+
+    /// The number of distinct cheapest paths from the start to `end`.
+    pub fn count_paths(&self, end: &S) -> usize {
+        fn count<S: Clone + Eq + Hash, C: Cost>(
+            paths: &ShortestPaths<S, C>,
+            state: &S,
+            cache: &mut HashMap<S, usize>,
+        ) -> usize {
+            if let Some(&known) = cache.get(state) {
+                return known;
+            }
+            let preds = paths.predecessors(state);
+            let total = match preds.is_empty() {
+                true => 1,
+                false => preds.iter().map(|prev| count(paths, prev, cache)).sum(),
+            };
+            cache.insert(state.clone(), total);
+            return total;
+        }
+
+        if !self.costs.contains_key(end) {
+            return 0;
+        }
+        return count(self, end, &mut HashMap::new());
+    }
+
+    /// Every state on at least one cheapest path from the start to any of `ends`.
+    pub fn states_on_paths(&self, ends: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = ends
+            .into_iter()
+            .filter(|end| self.costs.contains_key(end))
+            .collect::<Vec<_>>();
+
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+
+        return seen;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// A line of states `0..len` where each state steps to its neighbours.
+    fn line(len: i32) -> impl FnMut(&i32) -> Vec<i32> {
+        move |&n| {
+            [n - 1, n + 1]
+                .into_iter()
+                .filter(|n| (0..len).contains(n))
+                .collect()
+        }
+    }
+
+    /// Two routes of equal cost from 0 to 3, through 1 or 2, plus a costlier
+    /// direct edge.
+    fn diamond(&n: &u8) -> Vec<(u8, u32)> {
+        match n {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_distances() {
+        let dists = bfs(2, line(5));
+        assert_eq!(dists.len(), 5);
+        assert_eq!(dists[&0], 2);
+        assert_eq!(dists[&4], 2);
+    }
+
+    #[test]
+    fn bfs_path_to_goal() {
+        assert_eq!(bfs_path(0, line(5), |&n| n == 3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(bfs_path(0, line(5), |&n| n == 9), None);
+    }
+
+    #[test]
+    fn astar_and_dijkstra() {
+        let goal = |&n: &u8| n == 3;
+        let heuristic = |&n: &u8| u32::from(n != 3);
+        let (path, cost) = astar(0, diamond, heuristic, goal).unwrap();
+        assert_eq!((path.len(), path[0], path[2], cost), (3, 0, 3, 2));
+        assert_eq!(dijkstra(0, diamond, goal).map(|(_, cost)| cost), Some(2));
+        assert_eq!(dijkstra(3, diamond, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn dijkstra_all_keeps_ties() {
+        let paths = dijkstra_all(0, diamond);
+        assert_eq!(paths.cost(&3), Some(2));
+        assert_eq!(
+            paths.predecessors(&3).iter().collect::<HashSet<_>>(),
+            HashSet::from([&1, &2])
+        );
+        assert_eq!(paths.count_paths(&3), 2);
+        assert_eq!(paths.paths_to(&3).len(), 2);
+        assert_eq!(paths.path_to(&3).map(|path| path.len()), Some(3));
+        assert_eq!(paths.states_on_paths([3]), HashSet::from([0, 1, 2, 3]));
+        assert_eq!(paths.count_paths(&9), 0);
+    }
+
+    #[test]
+    fn dijkstra_all_counts_lattice_paths() {
+        // moving right or down across a 3x3 grid reaches the far corner in
+        // 4 choose 2 ways
+        let paths = dijkstra_all((0u8, 0u8), |&(x, y)| {
+            [(x + 1, y), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| x < 3 && y < 3)
+                .map(|next| (next, 1u32))
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(paths.cost(&(2, 2)), Some(4));
+        assert_eq!(paths.count_paths(&(2, 2)), 6);
+        assert_eq!(paths.paths_to(&(2, 2)).len(), 6);
+        assert_eq!(paths.count_paths(&(1, 2)), 3);
+        assert_eq!(paths.states_on_paths([(2, 2)]).len(), 9);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "step costs must be positive")]
+    fn dijkstra_all_zero_cost_step() {
+        dijkstra_all(0u8, |&n| match n {
+            0 => vec![(1, 1u32)],
+            1 => vec![(2, 0)],
+            _ => vec![],
+        });
+    }
+}
@@ -0,0 +1,57 @@
+use std::{any::Any, fmt::Display};
+
+use crate::{AdventOfCode, AocResult, Override};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+}
+
+/// A type-erased handle to an `AdventOfCode` implementor, so days with
+/// different parsed and answer types can be registered side by side.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: usize,
+    parse: fn(&str) -> AocResult<Box<dyn Any>>,
+    solve: fn(&dyn Any, Part, &[Override]) -> AocResult<String>,
+}
+
+impl Solution {
+    pub fn new<T: AdventOfCode>(day: usize) -> Self {
+        Self {
+            day,
+            parse: |input| T::parse(input).map(|parsed| Box::new(parsed) as Box<dyn Any>),
+            solve: |parsed, part, overrides| {
+                let parsed = parsed
+                    .downcast_ref::<T::Parsed>()
+                    .expect("parsed input comes from the same solution");
+                T::answer(parsed, part, overrides)
+            },
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> AocResult<Box<dyn Any>> {
+        (self.parse)(input).map_err(|err| err.in_day(self.day))
+    }
+
+    /// Solves one part from the output of this solution's `parse`.
+    pub fn solve(&self, parsed: &dyn Any, part: Part, overrides: &[Override]) -> AocResult<String> {
+        (self.solve)(parsed, part, overrides).map_err(|err| err.in_day(self.day))
+    }
+}
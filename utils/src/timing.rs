@@ -0,0 +1,181 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs,
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{AocError, AocResult, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Input,
+    Parse,
+    Part(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Input => f.pad("input"),
+            Phase::Parse => f.pad("parse"),
+            Phase::Part(part) => part.fmt(f),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "input" => Ok(Phase::Input),
+            "parse" => Ok(Phase::Parse),
+            "1" => Ok(Phase::Part(Part::One)),
+            "2" => Ok(Phase::Part(Part::Two)),
+            _ => Err(AocError::parse(format!("invalid phase {s:?}"))),
+        }
+    }
+}
+
+/// Wall-clock samples from running the same phase one or more times.
+#[derive(Debug, Clone)]
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    /// Runs `f` `repeat` times (at least once) and returns the first result
+    /// together with the sorted samples.
+    pub fn measure<T>(repeat: usize, mut f: impl FnMut() -> T) -> (T, Timing) {
+        let mut samples = Vec::with_capacity(repeat.max(1));
+
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+
+        for _ in 1..repeat {
+            let start = Instant::now();
+            f();
+            samples.push(start.elapsed());
+        }
+
+        samples.sort();
+        return (result, Timing { samples });
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+}
+
+/// Median timings keyed by day and phase, stored as one
+/// `day<TAB>phase<TAB>nanoseconds` line per entry.
+#[derive(Debug, Default, Clone)]
+pub struct Benchmarks {
+    entries: BTreeMap<(usize, Phase), Duration>,
+}
+
+impl Benchmarks {
+    pub fn load(path: &Path) -> AocResult<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| AocError::input(format!("failed to read {}: {err}", path.display())))?;
+
+        let mut benchmarks = Benchmarks::default();
+        for (i, line) in contents.lines().enumerate() {
+            let mut fields = line.split('\t');
+            let (Some(day), Some(phase), Some(nanos), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(AocError::parse(format!("invalid benchmark {line:?}")).at_line(i + 1));
+            };
+
+            let day = day.parse().ok();
+            let phase = phase.parse().ok();
+            let nanos = nanos.parse().ok();
+            let (Some(day), Some(phase), Some(nanos)) = (day, phase, nanos) else {
+                return Err(AocError::parse(format!("invalid benchmark {line:?}")).at_line(i + 1));
+            };
+
+            benchmarks.insert(day, phase, Duration::from_nanos(nanos));
+        }
+
+        return Ok(benchmarks);
+    }
+
+    pub fn save(&self, path: &Path) -> AocResult<()> {
+        let contents = self
+            .entries
+            .iter()
+            .map(|((day, phase), duration)| format!("{day}\t{phase}\t{}\n", duration.as_nanos()))
+            .collect::<String>();
+
+        fs::write(path, contents)
+            .map_err(|err| AocError::input(format!("failed to write {}: {err}", path.display())))
+    }
+
+    pub fn insert(&mut self, day: usize, phase: Phase, duration: Duration) {
+        self.entries.insert((day, phase), duration);
+    }
+
+    pub fn get(&self, day: usize, phase: Phase) -> Option<Duration> {
+        self.entries.get(&(day, phase)).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use super::{Benchmarks, Phase};
+    use crate::Part;
+
+    #[test]
+    fn benchmarks_round_trip() {
+        let path = env::temp_dir().join(format!("aoc-bench-{}.tsv", std::process::id()));
+        let mut benchmarks = Benchmarks::default();
+        benchmarks.insert(1, Phase::Parse, Duration::from_nanos(1500));
+        benchmarks.insert(14, Phase::Part(Part::Two), Duration::from_millis(3));
+        benchmarks.save(&path).unwrap();
+
+        let loaded = Benchmarks::load(&path);
+        fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+        assert_eq!(
+            loaded.get(1, Phase::Parse),
+            Some(Duration::from_nanos(1500))
+        );
+        assert_eq!(
+            loaded.get(14, Phase::Part(Part::Two)),
+            Some(Duration::from_millis(3))
+        );
+        assert_eq!(loaded.get(1, Phase::Input), None);
+    }
+
+    #[test]
+    fn benchmarks_malformed() {
+        let path = env::temp_dir().join(format!("aoc-bench-bad-{}.tsv", std::process::id()));
+        for (contents, line) in [
+            ("1\tparse\t10\n1\t3\t10\n", 2),
+            ("1\tparse\n", 1),
+            ("1\tparse\t10\textra\n", 1),
+            ("x\tparse\t10\n", 1),
+            ("1\tparse\t-10\n", 1),
+        ] {
+            fs::write(&path, contents).unwrap();
+            let err = Benchmarks::load(&path).unwrap_err();
+            assert_eq!(err.line, Some(line), "{contents:?}");
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}